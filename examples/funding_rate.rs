@@ -1,10 +1,8 @@
 use anyhow::Error;
-use bybit_async::{
-    rest,
-    Bybit,
-};
+use bybit_async::{models::Product, Bybit};
 use chrono::{DateTime, Utc};
 use fehler::throws;
+use futures::TryStreamExt;
 
 #[throws(Error)]
 #[tokio::main]
@@ -13,31 +11,14 @@ async fn main() {
 
     let bybit = Bybit::new();
 
-    // let resp = bybit
-    //     .request(::FundingRateRequest {
-    //         symbol: Some("SOLUSDT".into()),
-    //         start_time: Some(
-    //             DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z")
-    //                 .unwrap()
-    //                 .with_timezone(&Utc),
-    //         ),
-    //         limit: Some(1000),
-    //         ..Default::default()
-    //     })
-    //     .await?;
-    // println!("{resp:?}");
-    //
-    // let resp = bybit
-    //     .request(coinm::FundingRateRequest {
-    //         symbol: "SOLUSD_PERP".into(),
-    //         start_time: Some(
-    //             DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z")
-    //                 .unwrap()
-    //                 .with_timezone(&Utc),
-    //         ),
-    //         limit: Some(1000),
-    //         ..Default::default()
-    //     })
-    //     .await?;
-    // println!("{resp:?}");
+    let start = DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let rates: Vec<_> = bybit
+        .funding_history(Product::UsdMFutures, "SOLUSDT", Some(start), None)
+        .try_collect()
+        .await?;
+    for rate in rates.iter().rev() {
+        println!("{} {}", rate.funding_rate_timestamp, rate.funding_rate);
+    }
 }
@@ -1,11 +1,10 @@
 use anyhow::Error;
 use bybit_async::{
-    models::{OrderType, Side, TimeInForce},
-    rest,
-    Bybit,
+    models::{OrderRef, OrderType, Product, Side, TimeInForce},
+    rest, Bybit,
 };
 use fehler::throws;
-use rust_decimal::{prelude::FromPrimitive, Decimal};
+use rust_decimal::Decimal;
 use std::env::var;
 
 #[throws(Error)]
@@ -15,13 +14,14 @@ async fn main() {
 
     let bybit = Bybit::with_key_and_secret(&var("BYBIT_KEY")?, &var("BYBIT_SECRET")?);
     let resp = bybit
-        .request(rest::trade::NewOrderRequest {
-            symbol: "ethusdt".into(),
-            r#type: OrderType::Limit,
+        .request(rest::trade::CreateOrderRequest {
+            category: Product::UsdMFutures,
+            symbol: "ETHUSDT".into(),
+            order_type: OrderType::Limit,
             side: Side::Buy,
 
-            price: Decimal::from_f64(1500.),
-            quantity: Decimal::from_f64(0.004),
+            price: Some(Decimal::new(1500, 0)),
+            qty: Decimal::new(1, 2),
             time_in_force: Some(TimeInForce::GTC),
             ..Default::default()
         })
@@ -30,8 +30,9 @@ async fn main() {
 
     let resp = bybit
         .request(rest::trade::CancelOrderRequest {
-            symbol: "ethusdt".into(),
-            order_id: Some(resp.order_id),
+            category: Product::UsdMFutures,
+            symbol: "ETHUSDT".into(),
+            order: OrderRef::OrderId(resp.order_id),
             ..Default::default()
         })
         .await?;
@@ -32,10 +32,10 @@ async fn main() {
         println!("connected");
         ws.subscribe(["order"].to_vec()).await?;
         let msg = ws.next().await.expect("ws exited")?;
-        println!("{msg:?}");   // connected success message
+        println!("{msg:?}"); // connected success message
         println!("subscribed");
         let msg = ws.next().await.expect("ws exited")?;
-        println!("{msg:?}");  // subscribed success message
+        println!("{msg:?}"); // subscribed success message
 
         // order updates
         let msg = ws.next().await.expect("ws exited")?;
@@ -1,18 +1,72 @@
 use crate::models::Product;
 
-const REST_API_ENDPOINT: &str = "https://api.bybit.com";
-const SPOT_WS_ENDPOINT: &str = "wss://stream.bybit.com/v5/public/spot";
-const USDM_FUTURES_WS_ENDPOINT: &str = "wss://stream.bybit.com/v5/public/linear";
-const COINM_FUTURES_WS_ENDPOINT: &str = "wss://stream.bybit.com/v5/public/inverse";
-const EUROPEAN_OPTIONS_WS_ENDPOINT: &str = "wss://stream.bybit.com/v5/public/option";
-const PRIVATE_WS_ENDPOINT: &str = "wss://stream.bybit.com/v5/private";
+/// The Bybit deployment to connect to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Environment {
+    #[default]
+    Mainnet,
+    /// Mainnet through the backup `bytick.com` domain
+    Bytick,
+    Testnet,
+    /// Demo trading on mainnet market data. Demo only hosts the private stream,
+    /// public streams are served by mainnet.
+    Demo,
+    Netherlands,
+    HongKong,
+    Turkey,
+    Kazakhstan,
+    Georgia,
+    Uae,
+    Eea,
+}
+
+impl Environment {
+    pub fn rest_api_endpoint(self) -> String {
+        match self {
+            Environment::Testnet => "https://api-testnet.bybit.com".into(),
+            Environment::Demo => "https://api-demo.bybit.com".into(),
+            _ => format!("https://api.{}", self.domain()),
+        }
+    }
+
+    pub fn public_ws_endpoint(self, product: Product) -> String {
+        let host = match self {
+            Environment::Testnet => "stream-testnet.bybit.com".into(),
+            Environment::Demo => "stream.bybit.com".into(),
+            _ => format!("stream.{}", self.domain()),
+        };
+        format!("wss://{host}/v5/public/{product}")
+    }
+
+    pub fn private_ws_endpoint(self) -> String {
+        match self {
+            Environment::Testnet => "wss://stream-testnet.bybit.com/v5/private".into(),
+            Environment::Demo => "wss://stream-demo.bybit.com/v5/private".into(),
+            _ => format!("wss://stream.{}/v5/private", self.domain()),
+        }
+    }
+
+    fn domain(self) -> &'static str {
+        match self {
+            Environment::Mainnet | Environment::Testnet | Environment::Demo => "bybit.com",
+            Environment::Bytick => "bytick.com",
+            Environment::Netherlands => "bybit.nl",
+            Environment::HongKong => "byhkbit.com",
+            Environment::Turkey => "bybit-tr.com",
+            Environment::Kazakhstan => "bybit.kz",
+            Environment::Georgia => "bybitgeorgia.ge",
+            Environment::Uae => "bybit.ae",
+            Environment::Eea => "bybit.eu",
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub product: Product,
 
-    pub rest_api_endpoint: String,
     /// Unified REST API endpoint
+    pub rest_api_endpoint: String,
     pub spot_ws_endpoint: String,
     pub usdm_futures_ws_endpoint: String,
     pub coinm_futures_ws_endpoint: String,
@@ -30,16 +84,17 @@ impl Default for Config {
         Self {
             product: Product::Spot,
 
-            rest_api_endpoint: REST_API_ENDPOINT.into(),
-            spot_ws_endpoint: SPOT_WS_ENDPOINT.into(),
-            usdm_futures_ws_endpoint: USDM_FUTURES_WS_ENDPOINT.into(),
-            coinm_futures_ws_endpoint: COINM_FUTURES_WS_ENDPOINT.into(),
-            european_options_ws_endpoint: EUROPEAN_OPTIONS_WS_ENDPOINT.into(),
-            private_ws_endpoint: PRIVATE_WS_ENDPOINT.into(),
+            rest_api_endpoint: String::new(),
+            spot_ws_endpoint: String::new(),
+            usdm_futures_ws_endpoint: String::new(),
+            coinm_futures_ws_endpoint: String::new(),
+            european_options_ws_endpoint: String::new(),
+            private_ws_endpoint: String::new(),
             recv_window: 5000,
             api_key: None,
             api_secret: None,
         }
+        .set_environment(Environment::Mainnet)
     }
 }
 
@@ -60,11 +115,109 @@ impl Config {
     }
 
     pub fn testnet() -> Self {
-        // Self::default()
-        //     .set_rest_api_endpoint("https://testnet.bybit.vision")
-        //     .set_ws_endpoint("wss://testnet.bybit.vision/ws")
-        //     .set_futures_rest_api_endpoint("https://testnet.bybitfuture.com")
-        //     .set_futures_ws_endpoint("https://testnet.bybitfuture.com/ws")
-        todo!()
+        Self::default().set_environment(Environment::Testnet)
+    }
+
+    pub fn demo() -> Self {
+        Self::default().set_environment(Environment::Demo)
+    }
+
+    /// Points every REST and websocket endpoint to the given environment.
+    pub fn set_environment(self, environment: Environment) -> Self {
+        Self {
+            rest_api_endpoint: environment.rest_api_endpoint(),
+            spot_ws_endpoint: environment.public_ws_endpoint(Product::Spot),
+            usdm_futures_ws_endpoint: environment.public_ws_endpoint(Product::UsdMFutures),
+            coinm_futures_ws_endpoint: environment.public_ws_endpoint(Product::CoinMFutures),
+            european_options_ws_endpoint: environment.public_ws_endpoint(Product::EuropeanOptions),
+            private_ws_endpoint: environment.private_ws_endpoint(),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Config, Environment};
+
+    #[test]
+    fn testnet() {
+        let config = Config::testnet();
+        assert_eq!(config.rest_api_endpoint, "https://api-testnet.bybit.com");
+        assert_eq!(
+            config.spot_ws_endpoint,
+            "wss://stream-testnet.bybit.com/v5/public/spot"
+        );
+        assert_eq!(
+            config.usdm_futures_ws_endpoint,
+            "wss://stream-testnet.bybit.com/v5/public/linear"
+        );
+        assert_eq!(
+            config.coinm_futures_ws_endpoint,
+            "wss://stream-testnet.bybit.com/v5/public/inverse"
+        );
+        assert_eq!(
+            config.european_options_ws_endpoint,
+            "wss://stream-testnet.bybit.com/v5/public/option"
+        );
+        assert_eq!(
+            config.private_ws_endpoint,
+            "wss://stream-testnet.bybit.com/v5/private"
+        );
+    }
+
+    #[test]
+    fn demo() {
+        let config = Config::demo();
+        assert_eq!(config.rest_api_endpoint, "https://api-demo.bybit.com");
+        assert_eq!(
+            config.spot_ws_endpoint,
+            "wss://stream.bybit.com/v5/public/spot"
+        );
+        assert_eq!(
+            config.usdm_futures_ws_endpoint,
+            "wss://stream.bybit.com/v5/public/linear"
+        );
+        assert_eq!(
+            config.coinm_futures_ws_endpoint,
+            "wss://stream.bybit.com/v5/public/inverse"
+        );
+        assert_eq!(
+            config.european_options_ws_endpoint,
+            "wss://stream.bybit.com/v5/public/option"
+        );
+        assert_eq!(
+            config.private_ws_endpoint,
+            "wss://stream-demo.bybit.com/v5/private"
+        );
+    }
+
+    #[test]
+    fn regional() {
+        let config = Config::private_new("key".into(), "secret".into())
+            .set_environment(Environment::Netherlands);
+        assert_eq!(config.rest_api_endpoint, "https://api.bybit.nl");
+        assert_eq!(
+            config.spot_ws_endpoint,
+            "wss://stream.bybit.nl/v5/public/spot"
+        );
+        assert_eq!(
+            config.usdm_futures_ws_endpoint,
+            "wss://stream.bybit.nl/v5/public/linear"
+        );
+        assert_eq!(
+            config.coinm_futures_ws_endpoint,
+            "wss://stream.bybit.nl/v5/public/inverse"
+        );
+        assert_eq!(
+            config.european_options_ws_endpoint,
+            "wss://stream.bybit.nl/v5/public/option"
+        );
+        assert_eq!(
+            config.private_ws_endpoint,
+            "wss://stream.bybit.nl/v5/private"
+        );
+        // Switching environments keeps the credentials
+        assert_eq!(config.api_key.as_deref(), Some("key"));
     }
 }
@@ -1,12 +1,19 @@
 use reqwest::header::InvalidHeaderValue;
 use reqwest::StatusCode;
 use serde::Deserialize;
+use serde_json::Value;
 use thiserror::Error;
 
+/// The error part of a V5 response envelope, i.e. a response with non-zero `retCode`.
 #[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct BybitResponseError {
-    pub code: i64,
-    pub msg: String,
+    pub ret_code: i64,
+    pub ret_msg: String,
+    #[serde(default)]
+    pub ret_ext_info: Value,
+    #[serde(default)]
+    pub time: u64,
 }
 
 #[derive(Debug, Error)]
@@ -29,8 +36,18 @@ pub enum BybitError {
     StartWebsocketError(StatusCode, String),
     #[error("The field for the given event type {0} in user data stream is empty")]
     EmptyUserDataStream(String),
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    /// A non-2xx response without a V5 envelope, e.g. the IP rate limit or a gateway error.
+    #[error("Bybit returns HTTP {0}: {1}")]
+    HttpStatus(StatusCode, String),
     #[error("Bybit returns error: {code} - {msg}")]
-    BybitResponse { code: i64, msg: String },
+    BybitResponse {
+        code: i64,
+        msg: String,
+        ext_info: Value,
+        time: u64,
+    },
 
     #[error(transparent)]
     Websocket(#[from] tungstenite::Error),
@@ -47,8 +64,211 @@ pub enum BybitError {
 impl From<BybitResponseError> for BybitError {
     fn from(v: BybitResponseError) -> Self {
         Self::BybitResponse {
-            code: v.code,
-            msg: v.msg,
+            code: v.ret_code,
+            msg: v.ret_msg,
+            ext_info: v.ret_ext_info,
+            time: v.time,
         }
     }
 }
+
+impl BybitError {
+    /// The classified `retCode` if this error is returned by Bybit.
+    pub fn code(&self) -> Option<BybitErrorCode> {
+        match self {
+            Self::BybitResponse { code, .. } => Some((*code).into()),
+            _ => None,
+        }
+    }
+
+    pub fn category(&self) -> Option<ErrorCategory> {
+        self.code().map(BybitErrorCode::category)
+    }
+
+    /// Also covers the IP rate limit, which Bybit answers with HTTP 403 instead of a `retCode`.
+    pub fn is_rate_limited(&self) -> bool {
+        match self {
+            Self::HttpStatus(status, _) => {
+                matches!(
+                    *status,
+                    StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS
+                )
+            }
+            _ => self.code().is_some_and(BybitErrorCode::is_rate_limited),
+        }
+    }
+
+    /// Whether sending the same request again later may succeed:
+    /// rate limits, busy servers and network timeouts.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::BybitResponse { .. } => self.code().is_some_and(BybitErrorCode::is_retryable),
+            Self::HttpStatus(status, _) => self.is_rate_limited() || status.is_server_error(),
+            Self::Reqwest(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    Auth,
+    RateLimit,
+    InsufficientBalance,
+    InvalidParameter,
+    OrderNotFound,
+    PositionModeMismatch,
+    SystemBusy,
+    Other,
+}
+
+macro_rules! error_codes {
+    ($($(#[$meta:meta])* $name:ident = $code:literal => $category:ident,)*) => {
+        /// Known V5 `retCode`s. Codes not listed here are kept as `Unknown`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum BybitErrorCode {
+            $($(#[$meta])* $name,)*
+            Unknown(i64),
+        }
+
+        impl BybitErrorCode {
+            pub fn code(self) -> i64 {
+                match self {
+                    $(Self::$name => $code,)*
+                    Self::Unknown(code) => code,
+                }
+            }
+
+            pub fn category(self) -> ErrorCategory {
+                match self {
+                    $(Self::$name => ErrorCategory::$category,)*
+                    Self::Unknown(_) => ErrorCategory::Other,
+                }
+            }
+        }
+
+        impl From<i64> for BybitErrorCode {
+            fn from(code: i64) -> Self {
+                match code {
+                    $($code => Self::$name,)*
+                    code => Self::Unknown(code),
+                }
+            }
+        }
+    };
+}
+
+// https://bybit-exchange.github.io/docs/v5/error
+error_codes! {
+    ServerTimeout = 10000 => SystemBusy,
+    /// Also returned when the position idx does not match the position mode.
+    InvalidParameter = 10001 => InvalidParameter,
+    /// The request timestamp is out of `recv_window`.
+    InvalidTimestamp = 10002 => Auth,
+    InvalidApiKey = 10003 => Auth,
+    InvalidSignature = 10004 => Auth,
+    PermissionDenied = 10005 => Auth,
+    TooManyVisits = 10006 => RateLimit,
+    AuthenticationFailed = 10007 => Auth,
+    IpBanned = 10009 => Auth,
+    UnmatchedIp = 10010 => Auth,
+    DuplicateRequest = 10014 => InvalidParameter,
+    ServerError = 10016 => SystemBusy,
+    RouteNotFound = 10017 => InvalidParameter,
+    IpRateLimit = 10018 => RateLimit,
+    TradingBanned = 10027 => Auth,
+    InvalidSymbol = 10029 => InvalidParameter,
+    OrderNotExists = 110001 => OrderNotFound,
+    PriceOutOfRange = 110003 => InvalidParameter,
+    InsufficientWalletBalance = 110004 => InsufficientBalance,
+    InsufficientAvailableBalance = 110006 => InsufficientBalance,
+    AvailableBalanceNotEnough = 110007 => InsufficientBalance,
+    OrderCompletedOrCancelled = 110008 => OrderNotFound,
+    InsufficientBalanceForOrderCost = 110012 => InsufficientBalance,
+    ReduceOnlyNotSatisfied = 110017 => InvalidParameter,
+    OneWayModeOnly = 110023 => PositionModeMismatch,
+    PositionModeNotSwitchable = 110024 => PositionModeMismatch,
+    PositionModeNotModified = 110025 => PositionModeMismatch,
+    LeverageNotModified = 110043 => InvalidParameter,
+    SpotTooManyNewOrders = 170005 => RateLimit,
+    SpotBackendTimeout = 170007 => SystemBusy,
+    SpotInsufficientBalance = 170131 => InsufficientBalance,
+    SpotOrderCreationTimeout = 170146 => SystemBusy,
+    SpotOrderCancellationTimeout = 170147 => SystemBusy,
+    SpotOrderNotExists = 170213 => OrderNotFound,
+}
+
+impl BybitErrorCode {
+    pub fn is_rate_limited(self) -> bool {
+        self.category() == ErrorCategory::RateLimit
+    }
+
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self.category(),
+            ErrorCategory::RateLimit | ErrorCategory::SystemBusy
+        )
+    }
+}
+
+impl From<BybitErrorCode> for i64 {
+    fn from(v: BybitErrorCode) -> Self {
+        v.code()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BybitError, BybitErrorCode, BybitResponseError, ErrorCategory};
+    use reqwest::StatusCode;
+    use serde_json::Value;
+
+    fn response_error(code: i64) -> BybitError {
+        BybitResponseError {
+            ret_code: code,
+            ret_msg: String::new(),
+            ret_ext_info: Value::Null,
+            time: 0,
+        }
+        .into()
+    }
+
+    #[test]
+    fn classify() {
+        let e = response_error(10006);
+        assert_eq!(e.code(), Some(BybitErrorCode::TooManyVisits));
+        assert!(e.is_rate_limited());
+        assert!(e.is_retryable());
+
+        let e = response_error(170131);
+        assert_eq!(e.category(), Some(ErrorCategory::InsufficientBalance));
+        assert!(!e.is_retryable());
+
+        assert!(!BybitError::MissingApiKey.is_retryable());
+        assert_eq!(BybitError::MissingApiKey.code(), None);
+    }
+
+    #[test]
+    fn classify_http_status() {
+        let e = BybitError::HttpStatus(StatusCode::FORBIDDEN, "access too frequent".into());
+        assert!(e.is_rate_limited());
+        assert!(e.is_retryable());
+        assert_eq!(e.code(), None);
+
+        let e = BybitError::HttpStatus(StatusCode::BAD_GATEWAY, String::new());
+        assert!(!e.is_rate_limited());
+        assert!(e.is_retryable());
+
+        let e = BybitError::HttpStatus(StatusCode::NOT_FOUND, String::new());
+        assert!(!e.is_retryable());
+    }
+
+    #[test]
+    fn unknown_round_trip() {
+        let code = BybitErrorCode::from(131212);
+        assert_eq!(code, BybitErrorCode::Unknown(131212));
+        assert_eq!(code.category(), ErrorCategory::Other);
+        assert_eq!(i64::from(code), 131212);
+        assert_eq!(i64::from(BybitErrorCode::from(110007)), 110007);
+    }
+}
@@ -1,4 +1,5 @@
 //#![deny(unstable_features, unused_must_use, unused_mut, unused_imports, unused_import_braces)]
+#![allow(clippy::result_large_err)]
 //! # Bybit Async
 //! Unofficial Rust Library for the [Bybit API](https://github.com/bybit-exchange/bybit-official-api-docs)
 //! with Async/Await and ergonomic design.
@@ -14,21 +15,22 @@
 //! the struct for REST requests and it only exposes one function: `fn request`. Additionally,
 //! which API endpoint to call and what parameter to carry are all stored in the type information.
 //! for example, creating a new order is
-//! ```rust
+//! ```rust,ignore
 //! let bybit = Bybit::new();
-//! bybit.request(usdm::NewOrderRequest {
-//!     symbol: "ethusdt".into(),
-//!     r#type: OrderType::Limit,
+//! bybit.request(trade::CreateOrderRequest {
+//!     category: Product::UsdMFutures,
+//!     symbol: "ETHUSDT".into(),
+//!     order_type: OrderType::Limit,
 //!     side: Side::Buy,
 //!
-//!     price: Decimal::from_f64(1500.),
-//!     quantity: Decimal::from_f64(0.004),
+//!     price: Some(Decimal::new(1500, 0)),
+//!     qty: Decimal::new(1, 2),
 //!     time_in_force: Some(TimeInForce::GTC),
 //!     ..Default::default()
 //! })
 //! ```
 //!
-//! As you can see, `usdm::NewOrderRequest` itself knows which endpoint ("/fapi/v1/order") to send this request to. Moreover,
+//! As you can see, `trade::CreateOrderRequest` itself knows which endpoint ("/v5/order/create") to send this request to. Moreover,
 //! all the request structs have `Default` implemented, which allows you to express different parameter
 //! combinations without making the code verbose.
 //!
@@ -36,17 +38,18 @@
 //!
 //! ### Send a New Order and Cancel it
 //!
-//! ```rust
+//! ```rust,ignore
 //! async fn main() {
 //!    let bybit = Bybit::with_key_and_secret(&var("BYBIT_KEY")?, &var("BYBIT_SECRET")?);
 //!    let resp = bybit
-//!        .request(usdm::NewOrderRequest {
-//!            symbol: "ethusdt".into(),
-//!            r#type: OrderType::Limit,
+//!        .request(trade::CreateOrderRequest {
+//!            category: Product::UsdMFutures,
+//!            symbol: "ETHUSDT".into(),
+//!            order_type: OrderType::Limit,
 //!            side: Side::Buy,
 //!
-//!            price: Decimal::from_f64(1500.),
-//!            quantity: Decimal::from_f64(0.004),
+//!            price: Some(Decimal::new(1500, 0)),
+//!            qty: Decimal::new(1, 2),
 //!            time_in_force: Some(TimeInForce::GTC),
 //!            ..Default::default()
 //!        })
@@ -54,9 +57,10 @@
 //!    println!("{resp:?}");
 //!
 //!    let resp = bybit
-//!        .request(usdm::CancelOrderRequest {
-//!            symbol: "ethusdt".into(),
-//!            order_id: Some(resp.order_id),
+//!        .request(trade::CancelOrderRequest {
+//!            category: Product::UsdMFutures,
+//!            symbol: "ETHUSDT".into(),
+//!            order: OrderRef::OrderId(resp.order_id),
 //!            ..Default::default()
 //!        })
 //!        .await?;
@@ -66,7 +70,7 @@
 //!
 //! ### Listening to WS
 //!
-//! ```rust
+//! ```rust,ignore
 //! async fn main() {
 //!     let bybit = Bybit::with_key(&var("BYBIT_KEY")?);
 //!     let listen_key = bybit.request(StartUserDataStreamRequest {}).await?;
@@ -102,9 +106,9 @@ mod parser;
 pub mod rest;
 pub mod websocket;
 
-pub use config::Config;
-pub use error::{BybitError, BybitResponseError};
+pub use config::{Config, Environment};
+pub use error::{BybitError, BybitErrorCode, BybitResponseError, ErrorCategory};
 #[cfg(feature = "zero-copy")]
 pub use rest::C;
-pub use rest::{Bybit, RestResponse};
+pub use rest::{Bybit, BybitBuilder, RestResponse};
 pub use websocket::BybitWebsocket;
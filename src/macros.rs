@@ -2,35 +2,36 @@
 macro_rules! define_request {
     (
         Name => $name: ident;
-        Product => $product: expr;
         Method => $method: expr;
         Endpoint => $endpoint: expr;
         Signed => $signed: expr;
         Request => { $($req_def:tt)* };
         Response => { $($resp_def:tt)* };
+        $(Validate => $validate: path;)?
     ) => {
-        crate::define_request! {
+        $crate::define_request! {
             Name => $name;
-            Product => $product;
             Method => $method;
             Endpoint => $endpoint;
             Keyed => false;
             Signed => $signed;
             Request => { $($req_def)* };
             Response => { $($resp_def)* };
+            $(Validate => $validate;)?
         }
     };
     (
         Name => $name: ident;
-        Product => $product: expr;
         Method => $method: expr;
         Endpoint => $endpoint: expr;
         Keyed => $keyed: expr;
         Signed => $signed: expr;
         Request => { $($req_def:tt)* };
         Response => { $($resp_def:tt)* };
+        $(Validate => $validate: path;)?
     ) => {
         paste::paste! {
+            #[serde_with::skip_serializing_none]
             #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
             #[serde(rename_all = "camelCase")]
             pub struct [<$name Request>] {
@@ -43,61 +44,89 @@ macro_rules! define_request {
                 $($resp_def)*
             }
 
-            impl crate::rest::Request for [<$name Request>] {
-                const PRODUCT: crate::rest::Product = $product;
+            impl $crate::rest::Request for [<$name Request>] {
                 const ENDPOINT: &'static str = $endpoint;
                 const METHOD: reqwest::Method = $method;
                 const KEYED: bool = $keyed;
                 const SIGNED: bool = $signed;
                 type Response = [<$name Response>];
+                $(
+                    fn validate(&self) -> Result<(), $crate::BybitError> {
+                        $validate(self)
+                    }
+                )?
             }
         }
     };
 
     (
         Name => $name: ident;
-        Product => $product: expr;
         Method => $method: expr;
         Endpoint => $endpoint: expr;
         Signed => $signed: expr;
         Request => { $($req_def:tt)* };
         Response => $resp_ty: ty;
+        $(Validate => $validate: path;)?
     ) => {
-        crate::define_request! {
+        $crate::define_request! {
             Name => $name;
-            Product => $product;
             Method => $method;
             Endpoint => $endpoint;
             Keyed => false;
             Signed => $signed;
             Request => { $($req_def)* };
             Response => $resp_ty;
+            $(Validate => $validate;)?
         }
     };
     (
         Name => $name: ident;
-        Product => $product: expr;
         Method => $method: expr;
         Endpoint => $endpoint: expr;
         Keyed => $keyed: expr;
         Signed => $signed: expr;
         Request => { $($req_def:tt)* };
         Response => $resp_ty: ty;
+        $(Validate => $validate: path;)?
     ) => {
         paste::paste! {
+            #[serde_with::skip_serializing_none]
             #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
             #[serde(rename_all = "camelCase")]
             pub struct [<$name Request>] {
                 $($req_def)*
             }
 
-            impl crate::rest::Request for [<$name Request>] {
-                const PRODUCT: crate::rest::Product = $product;
+            impl $crate::rest::Request for [<$name Request>] {
                 const ENDPOINT: &'static str = $endpoint;
                 const METHOD: reqwest::Method = $method;
                 const KEYED: bool = $keyed;
                 const SIGNED: bool = $signed;
                 type Response = $resp_ty;
+                $(
+                    fn validate(&self) -> Result<(), $crate::BybitError> {
+                        $validate(self)
+                    }
+                )?
+            }
+        }
+    };
+}
+
+/// Implements `CursorRequest` for a request having a `cursor: Option<String>` field
+/// and `Page<$item>` as response.
+#[macro_export]
+macro_rules! impl_cursor_request {
+    ($req: ty => $item: ty) => {
+        impl $crate::rest::CursorRequest for $req {
+            type Item = $item;
+
+            fn set_cursor(&mut self, cursor: String) {
+                self.cursor = Some(cursor);
+            }
+
+            fn split_page(resp: $crate::models::Page<$item>) -> (Vec<$item>, String) {
+                (resp.list, resp.next_page_cursor)
             }
         }
     };
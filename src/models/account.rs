@@ -0,0 +1,168 @@
+use crate::models::{AccountType, UnifiedMarginStatus};
+use crate::parser::{string_or_decimal, string_or_decimal_opt, string_or_ts_milliseconds};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// https://bybit-exchange.github.io/docs/v5/account/wallet-balance
+/// The balance of one account. The account level fields are only set for unified accounts.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletBalance {
+    pub account_type: AccountType,
+    #[serde(default, rename = "accountIMRate", with = "string_or_decimal_opt")]
+    pub account_im_rate: Option<Decimal>,
+    #[serde(default, rename = "accountMMRate", with = "string_or_decimal_opt")]
+    pub account_mm_rate: Option<Decimal>,
+    #[serde(default, rename = "accountLTV", with = "string_or_decimal_opt")]
+    pub account_ltv: Option<Decimal>,
+    /// In USD, as are the other totals
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub total_equity: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub total_wallet_balance: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub total_margin_balance: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub total_available_balance: Option<Decimal>,
+    #[serde(default, rename = "totalPerpUPL", with = "string_or_decimal_opt")]
+    pub total_perp_upl: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub total_initial_margin: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub total_maintenance_margin: Option<Decimal>,
+    pub coin: Vec<CoinBalance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinBalance {
+    pub coin: String,
+    #[serde(with = "string_or_decimal")]
+    pub equity: Decimal,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub usd_value: Option<Decimal>,
+    #[serde(with = "string_or_decimal")]
+    pub wallet_balance: Decimal,
+    /// Classic spot accounts only
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub free: Option<Decimal>,
+    /// Locked by spot open orders
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub locked: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub borrow_amount: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub accrued_interest: Option<Decimal>,
+    #[serde(default, rename = "totalOrderIM", with = "string_or_decimal_opt")]
+    pub total_order_im: Option<Decimal>,
+    #[serde(default, rename = "totalPositionIM", with = "string_or_decimal_opt")]
+    pub total_position_im: Option<Decimal>,
+    #[serde(default, rename = "totalPositionMM", with = "string_or_decimal_opt")]
+    pub total_position_mm: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub unrealised_pnl: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub cum_realised_pnl: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub bonus: Option<Decimal>,
+    /// Whether the coin can be used as collateral at all
+    #[serde(default)]
+    pub margin_collateral: bool,
+    /// Whether the user enabled the coin as collateral
+    #[serde(default)]
+    pub collateral_switch: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginMode {
+    IsolatedMargin,
+    RegularMargin,
+    PortfolioMargin,
+}
+
+// https://bybit-exchange.github.io/docs/v5/account/account-info
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    pub unified_margin_status: UnifiedMarginStatus,
+    pub margin_mode: MarginMode,
+    #[serde(default)]
+    pub is_master_trader: bool,
+    /// "ON" or "OFF"
+    #[serde(default)]
+    pub spot_hedging_status: String,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub updated_time: DateTime<Utc>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/account/fee-rate
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeRate {
+    /// Empty for options, whose fees are per base coin
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub base_coin: String,
+    /// Negative for rebates
+    #[serde(with = "string_or_decimal")]
+    pub taker_fee_rate: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub maker_fee_rate: Decimal,
+}
+
+// https://bybit-exchange.github.io/docs/v5/account/collateral-info
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralInfo {
+    pub currency: String,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub hourly_borrow_rate: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub max_borrowing_amount: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub free_borrowing_limit: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub free_borrow_amount: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub borrow_amount: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub other_borrow_amount: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub available_to_borrow: Option<Decimal>,
+    pub borrowable: bool,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub borrow_usage_rate: Option<Decimal>,
+    /// Whether the coin can be used as collateral at all
+    pub margin_collateral: bool,
+    /// Whether the user enabled the coin as collateral
+    pub collateral_switch: bool,
+    /// Deprecated by tiered collateral ratios, kept for older accounts
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub collateral_ratio: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CollateralSwitch {
+    #[default]
+    On,
+    Off,
+}
+
+// https://bybit-exchange.github.io/docs/v5/asset/coin-greeks
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinGreeks {
+    pub base_coin: String,
+    #[serde(with = "string_or_decimal")]
+    pub total_delta: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub total_gamma: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub total_vega: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub total_theta: Decimal,
+}
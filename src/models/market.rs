@@ -1,35 +1,566 @@
+use crate::models::Side;
+use crate::parser::{
+    string_or_decimal, string_or_decimal_opt, string_or_opt, string_or_ts_milliseconds,
+    string_or_ts_milliseconds_opt,
+};
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, BoolFromInt};
 
-#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstrumentStatus {
+    PreLaunch,
+    Trading,
+    Settling,
+    Delivering,
+    Closed,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractType {
+    LinearPerpetual,
+    LinearFutures,
+    InversePerpetual,
+    InverseFutures,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionsType {
+    Call,
+    Put,
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/instrument
+// Fields only returned for some categories are optional.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Instrument {
+    pub symbol: String,
+    pub status: InstrumentStatus,
+    pub base_coin: String,
+    pub quote_coin: String,
+    pub settle_coin: Option<String>,
+    /// Linear and inverse only
+    pub contract_type: Option<ContractType>,
+    /// Option only
+    pub options_type: Option<OptionsType>,
+    #[serde(default, with = "string_or_ts_milliseconds_opt")]
+    pub launch_time: Option<DateTime<Utc>>,
+    /// `None` for perpetuals
+    #[serde(default, with = "string_or_ts_milliseconds_opt")]
+    pub delivery_time: Option<DateTime<Utc>>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub delivery_fee_rate: Option<Decimal>,
+    #[serde(default, with = "string_or_opt")]
+    pub price_scale: Option<u32>,
+    pub price_filter: PriceFilter,
+    pub lot_size_filter: LotSizeFilter,
+    /// Linear and inverse only
+    pub leverage_filter: Option<LeverageFilter>,
+    pub unified_margin_trade: Option<bool>,
+    /// Funding interval in minutes
+    pub funding_interval: Option<u64>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub upper_funding_rate: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub lower_funding_rate: Option<Decimal>,
+    pub copy_trading: Option<String>,
+    /// Spot only
+    pub margin_trading: Option<String>,
+    /// Spot only
+    pub innovation: Option<String>,
+}
+
+impl Instrument {
+    pub fn tick_size(&self) -> Decimal {
+        self.price_filter.tick_size
+    }
+
+    /// The quantity increment. Spot instruments express it as `basePrecision`.
+    pub fn qty_step(&self) -> Option<Decimal> {
+        self.lot_size_filter
+            .qty_step
+            .or(self.lot_size_filter.base_precision)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceFilter {
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub min_price: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub max_price: Option<Decimal>,
+    #[serde(with = "string_or_decimal")]
+    pub tick_size: Decimal,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct RateLimit {
-    interval: Interval,
-    interval_num: usize,
-    limit: usize,
-    rate_limit_type: RateLimitType,
+pub struct LotSizeFilter {
+    #[serde(with = "string_or_decimal")]
+    pub min_order_qty: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub max_order_qty: Decimal,
+    /// Linear, inverse and option
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub qty_step: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub post_only_max_order_qty: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub max_mkt_order_qty: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub min_notional_value: Option<Decimal>,
+    /// Spot only
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub base_precision: Option<Decimal>,
+    /// Spot only
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub quote_precision: Option<Decimal>,
+    /// Spot only
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub min_order_amt: Option<Decimal>,
+    /// Spot only
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub max_order_amt: Option<Decimal>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum Interval {
-    Minute,
+#[serde(rename_all = "camelCase")]
+pub struct LeverageFilter {
+    #[serde(with = "string_or_decimal")]
+    pub min_leverage: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub max_leverage: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub leverage_step: Decimal,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum KlineInterval {
+    #[serde(rename = "1")]
+    #[default]
+    Min1,
+    #[serde(rename = "3")]
+    Min3,
+    #[serde(rename = "5")]
+    Min5,
+    #[serde(rename = "15")]
+    Min15,
+    #[serde(rename = "30")]
+    Min30,
+    #[serde(rename = "60")]
+    Hour1,
+    #[serde(rename = "120")]
+    Hour2,
+    #[serde(rename = "240")]
+    Hour4,
+    #[serde(rename = "360")]
+    Hour6,
+    #[serde(rename = "720")]
+    Hour12,
+    #[serde(rename = "D")]
+    Day,
+    #[serde(rename = "W")]
+    Week,
+    #[serde(rename = "M")]
+    Month,
 }
 
-impl Default for Interval {
-    fn default() -> Self {
-        Interval::Minute
+impl KlineInterval {
+    /// The length of one candle. `Month` is taken as 31 days, the longest possible month.
+    pub fn duration(self) -> Duration {
+        match self {
+            KlineInterval::Min1 => Duration::minutes(1),
+            KlineInterval::Min3 => Duration::minutes(3),
+            KlineInterval::Min5 => Duration::minutes(5),
+            KlineInterval::Min15 => Duration::minutes(15),
+            KlineInterval::Min30 => Duration::minutes(30),
+            KlineInterval::Hour1 => Duration::hours(1),
+            KlineInterval::Hour2 => Duration::hours(2),
+            KlineInterval::Hour4 => Duration::hours(4),
+            KlineInterval::Hour6 => Duration::hours(6),
+            KlineInterval::Hour12 => Duration::hours(12),
+            KlineInterval::Day => Duration::days(1),
+            KlineInterval::Week => Duration::weeks(1),
+            KlineInterval::Month => Duration::days(31),
+        }
     }
 }
 
+/// A candle, deserialized from the `[startTime, open, high, low, close, volume, turnover]` row.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Kline {
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub start_time: DateTime<Utc>,
+    #[serde(with = "string_or_decimal")]
+    pub open: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub high: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub low: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub close: Decimal,
+    /// In base coin for linear and spot, in contracts for inverse
+    #[serde(with = "string_or_decimal")]
+    pub volume: Decimal,
+    /// In quote coin for linear and spot, in base coin for inverse
+    #[serde(with = "string_or_decimal")]
+    pub turnover: Decimal,
+}
+
+/// A mark, index or premium index price candle, deserialized from the
+/// `[startTime, open, high, low, close]` row.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceKline {
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub start_time: DateTime<Utc>,
+    #[serde(with = "string_or_decimal")]
+    pub open: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub high: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub low: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub close: Decimal,
+}
+
+/// A `[price, size]` row of the order book.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct OrderBookLevel {
+    #[serde(with = "string_or_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub size: Decimal,
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/orderbook
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OrderBook {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Best bid first
+    #[serde(rename = "b")]
+    pub bids: Vec<OrderBookLevel>,
+    /// Best ask first
+    #[serde(rename = "a")]
+    pub asks: Vec<OrderBookLevel>,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub ts: DateTime<Utc>,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub seq: u64,
+    /// The matching engine timestamp
+    #[serde(default, with = "string_or_ts_milliseconds_opt")]
+    pub cts: Option<DateTime<Utc>>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/tickers
+/// The tickers of one category, as the fields differ between categories.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "category", content = "list")]
+pub enum Tickers {
+    #[serde(rename = "spot")]
+    Spot(Vec<SpotTicker>),
+    #[serde(rename = "linear")]
+    Linear(Vec<FuturesTicker>),
+    #[serde(rename = "inverse")]
+    Inverse(Vec<FuturesTicker>),
+    #[serde(rename = "option")]
+    Option(Vec<OptionTicker>),
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotTicker {
+    pub symbol: String,
+    #[serde(rename = "bid1Price", with = "string_or_decimal")]
+    pub bid_price: Decimal,
+    #[serde(rename = "bid1Size", with = "string_or_decimal")]
+    pub bid_size: Decimal,
+    #[serde(rename = "ask1Price", with = "string_or_decimal")]
+    pub ask_price: Decimal,
+    #[serde(rename = "ask1Size", with = "string_or_decimal")]
+    pub ask_size: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub last_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub prev_price24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub price24h_pcnt: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub high_price24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub low_price24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub turnover24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub volume24h: Decimal,
+    /// Only for coins having a USD index
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub usd_index_price: Option<Decimal>,
+}
+
+/// Tickers of linear and inverse contracts. Funding fields are empty for dated futures
+/// and delivery fields are empty for perpetuals.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesTicker {
+    pub symbol: String,
+    #[serde(with = "string_or_decimal")]
+    pub last_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub index_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub mark_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub prev_price24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub price24h_pcnt: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub high_price24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub low_price24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub prev_price1h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub open_interest: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub open_interest_value: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub turnover24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub volume24h: Decimal,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub funding_rate: Option<Decimal>,
+    #[serde(default, with = "string_or_ts_milliseconds_opt")]
+    pub next_funding_time: Option<DateTime<Utc>>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub predicted_delivery_price: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub basis_rate: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub basis: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub delivery_fee_rate: Option<Decimal>,
+    #[serde(default, with = "string_or_ts_milliseconds_opt")]
+    pub delivery_time: Option<DateTime<Utc>>,
+    #[serde(rename = "bid1Price", with = "string_or_decimal")]
+    pub bid_price: Decimal,
+    #[serde(rename = "bid1Size", with = "string_or_decimal")]
+    pub bid_size: Decimal,
+    #[serde(rename = "ask1Price", with = "string_or_decimal")]
+    pub ask_price: Decimal,
+    #[serde(rename = "ask1Size", with = "string_or_decimal")]
+    pub ask_size: Decimal,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionTicker {
+    pub symbol: String,
+    #[serde(rename = "bid1Price", with = "string_or_decimal")]
+    pub bid_price: Decimal,
+    #[serde(rename = "bid1Size", with = "string_or_decimal")]
+    pub bid_size: Decimal,
+    #[serde(rename = "bid1Iv", with = "string_or_decimal")]
+    pub bid_iv: Decimal,
+    #[serde(rename = "ask1Price", with = "string_or_decimal")]
+    pub ask_price: Decimal,
+    #[serde(rename = "ask1Size", with = "string_or_decimal")]
+    pub ask_size: Decimal,
+    #[serde(rename = "ask1Iv", with = "string_or_decimal")]
+    pub ask_iv: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub last_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub high_price24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub low_price24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub mark_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub index_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub mark_iv: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub underlying_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub open_interest: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub turnover24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub volume24h: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub total_volume: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub total_turnover: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub delta: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub gamma: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub vega: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub theta: Decimal,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub predicted_delivery_price: Option<Decimal>,
+    #[serde(with = "string_or_decimal")]
+    pub change24h: Decimal,
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/recent-trade
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicTrade {
+    pub exec_id: String,
+    pub symbol: String,
+    #[serde(with = "string_or_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub size: Decimal,
+    pub side: Side,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub time: DateTime<Utc>,
+    pub is_block_trade: bool,
+    /// Option only
+    #[serde(default, rename = "mP", with = "string_or_decimal_opt")]
+    pub mark_price: Option<Decimal>,
+    /// Option only
+    #[serde(default, rename = "iP", with = "string_or_decimal_opt")]
+    pub index_price: Option<Decimal>,
+    /// Option only
+    #[serde(default, rename = "mIv", with = "string_or_decimal_opt")]
+    pub mark_iv: Option<Decimal>,
+    /// Option only
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub iv: Option<Decimal>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/history-fund-rate
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    #[serde(with = "string_or_decimal")]
+    pub funding_rate: Decimal,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub funding_rate_timestamp: DateTime<Utc>,
+}
+
+/// The `intervalTime` of open interest and the `period` of the long/short ratio.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IntervalTime {
+    #[serde(rename = "5min")]
+    #[default]
+    Min5,
+    #[serde(rename = "15min")]
+    Min15,
+    #[serde(rename = "30min")]
+    Min30,
+    #[serde(rename = "1h")]
+    Hour1,
+    #[serde(rename = "4h")]
+    Hour4,
+    #[serde(rename = "1d")]
+    Day,
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/open-interest
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    #[serde(with = "string_or_decimal")]
+    pub open_interest: Decimal,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/iv
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalVolatility {
+    /// In days
+    pub period: u64,
+    #[serde(with = "string_or_decimal")]
+    pub value: Decimal,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub time: DateTime<Utc>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/long-short-ratio
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRatio {
+    pub symbol: String,
+    #[serde(with = "string_or_decimal")]
+    pub buy_ratio: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub sell_ratio: Decimal,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/insurance
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Insurance {
+    pub coin: String,
+    /// Comma separated symbols sharing this pool
+    pub symbols: String,
+    #[serde(with = "string_or_decimal")]
+    pub balance: Decimal,
+    /// In USD
+    #[serde(with = "string_or_decimal")]
+    pub value: Decimal,
+}
+
+// https://bybit-exchange.github.io/docs/v5/market/risk-limit
+#[serde_as]
 #[derive(Deserialize, Serialize, Debug, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum RateLimitType {
-    RequestWeight,
-    Orders,
+#[serde(rename_all = "camelCase")]
+pub struct RiskLimit {
+    pub id: u64,
+    pub symbol: String,
+    /// The maximum position value of this tier
+    #[serde(with = "string_or_decimal")]
+    pub risk_limit_value: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub maintenance_margin: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub initial_margin: Decimal,
+    #[serde_as(as = "BoolFromInt")]
+    pub is_lowest_risk: bool,
+    #[serde(with = "string_or_decimal")]
+    pub max_leverage: Decimal,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub mm_deduction: Option<Decimal>,
 }
 
-impl Default for RateLimitType {
-    fn default() -> Self {
-        RateLimitType::RequestWeight
+impl RiskLimit {
+    /// The lowest tier of `symbol` in `tiers` whose limit covers a position of `notional`.
+    pub fn find_tier<'a>(
+        tiers: &'a [RiskLimit],
+        symbol: &str,
+        notional: Decimal,
+    ) -> Option<&'a RiskLimit> {
+        tiers
+            .iter()
+            .filter(|tier| tier.symbol == symbol && tier.risk_limit_value >= notional)
+            .min_by_key(|tier| tier.risk_limit_value)
     }
 }
+
+// https://bybit-exchange.github.io/docs/v5/market/delivery-price
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_decimal")]
+    pub delivery_price: Decimal,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub delivery_time: DateTime<Utc>,
+}
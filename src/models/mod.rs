@@ -1,55 +1,81 @@
-use crate::parser::string_or;
 use crate::parser::string_or_decimal;
-use crate::parser::string_or_decimal_opt;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
+pub mod account;
 pub mod market;
 pub mod order;
+pub mod position;
 
-#[derive(Copy, Clone, Debug)]
+/// Serialized as the V5 `category` parameter.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Product {
+    #[default]
+    #[serde(rename = "spot")]
     Spot,
+    #[serde(rename = "linear")]
     UsdMFutures,
+    #[serde(rename = "inverse")]
     CoinMFutures,
+    #[serde(rename = "option")]
     EuropeanOptions,
 }
 
+impl Product {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Product::Spot => "spot",
+            Product::UsdMFutures => "linear",
+            Product::CoinMFutures => "inverse",
+            Product::EuropeanOptions => "option",
+        }
+    }
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One page of a V5 list endpoint paged by `nextPageCursor`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    pub list: Vec<T>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
 #[derive(Serialize, Deserialize, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerTime {
     pub server_time: u64,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Side {
+    #[default]
     Buy,
     Sell,
 }
 
-impl Default for Side {
-    fn default() -> Self {
-        Self::Buy
-    }
-}
-
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum TimeInForce {
+    #[default]
     GTC,
     IOC,
     FOK,
     PostOnly,
+    /// Retail price improvement, for institutional market makers
+    RPI,
 }
 
-impl Default for TimeInForce {
-    fn default() -> Self {
-        Self::GTC
-    }
-}
-
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[allow(non_camel_case_types)]
 pub enum OrderType {
+    #[default]
     Market,
     Limit,
     TakeProfit,
@@ -64,254 +90,210 @@ pub enum OrderType {
     BidirectionalTpslOrder,
 }
 
-impl Default for OrderType {
+/// Identifies an order either by the `orderId` assigned by Bybit or by the user's `orderLinkId`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum OrderRef {
+    OrderId(String),
+    OrderLinkId(String),
+}
+
+/// A placeholder with an empty id, only there so requests embedding an `OrderRef` can be built
+/// with `..Default::default()`. Requests still carrying it are rejected with
+/// `BybitError::InvalidRequest` before being sent.
+impl Default for OrderRef {
     fn default() -> Self {
-        OrderType::Market
+        Self::OrderId(String::new())
     }
 }
 
-// {
-//       "filterType": "LOT_SIZE",
-//       "minQty": "0.00100000",
-//       "maxQty": "100000.00000000",
-//       "stepSize": "0.00100000"
-//     }
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum Filter {
-    #[serde(rename = "PRICE_FILTER")]
-    #[serde(rename_all = "camelCase")]
-    PriceFilter {
-        #[serde(with = "string_or_decimal")]
-        min_price: Decimal,
-        #[serde(with = "string_or_decimal")]
-        max_price: Decimal,
-        #[serde(with = "string_or_decimal")]
-        tick_size: Decimal,
-    },
-    #[serde(rename = "PERCENT_PRICE")]
-    #[serde(rename_all = "camelCase")]
-    PercentPrice {
-        #[serde(with = "string_or_decimal")]
-        multiplier_up: Decimal,
-        #[serde(with = "string_or_decimal")]
-        multiplier_down: Decimal,
-        avg_price_mins: Option<f64>,
-    },
-    #[serde(rename = "PERCENT_PRICE_BY_SIDE")]
-    #[serde(rename_all = "camelCase")]
-    PercentPriceBySide {
-        #[serde(with = "string_or_decimal")]
-        bid_multiplier_up: Decimal,
-        #[serde(with = "string_or_decimal")]
-        bid_multiplier_down: Decimal,
-        #[serde(with = "string_or_decimal")]
-        ask_multiplier_up: Decimal,
-        #[serde(with = "string_or_decimal")]
-        ask_multiplier_down: Decimal,
-        avg_price_mins: Option<f64>,
-    },
-    #[serde(rename = "LOT_SIZE")]
-    #[serde(rename_all = "camelCase")]
-    LotSize {
-        #[serde(with = "string_or_decimal")]
-        min_qty: Decimal,
-        #[serde(with = "string_or_decimal")]
-        max_qty: Decimal,
-        #[serde(with = "string_or_decimal")]
-        step_size: Decimal,
-    },
-    #[serde(rename = "MIN_NOTIONAL")]
-    #[serde(rename_all = "camelCase")]
-    MinNotional {
-        #[serde(default, with = "string_or_decimal_opt")]
-        notional: Option<Decimal>,
-        #[serde(default, with = "string_or_decimal_opt")]
-        min_notional: Option<Decimal>,
-        apply_to_market: Option<bool>,
-        avg_price_mins: Option<f64>,
-    },
-    #[serde(rename = "NOTIONAL")]
-    #[serde(rename_all = "camelCase")]
-    Notional {
-        #[serde(default, with = "string_or_decimal_opt")]
-        notional: Option<Decimal>,
-        #[serde(default, with = "string_or_decimal_opt")]
-        min_notional: Option<Decimal>,
-        apply_to_market: Option<bool>,
-        avg_price_mins: Option<f64>,
-    },
-    #[serde(rename = "ICEBERG_PARTS")]
-    #[serde(rename_all = "camelCase")]
-    IcebergParts { limit: Option<u16> },
-    #[serde(rename = "MAX_NUM_ORDERS")]
-    #[serde(rename_all = "camelCase")]
-    MaxNumOrders { max_num_orders: Option<u16> },
-    #[serde(rename = "MAX_NUM_ALGO_ORDERS")]
-    #[serde(rename_all = "camelCase")]
-    MaxNumAlgoOrders { max_num_algo_orders: Option<u16> },
-    #[serde(rename = "MAX_NUM_ICEBERG_ORDERS")]
-    #[serde(rename_all = "camelCase")]
-    MaxNumIcebergOrders { max_num_iceberg_orders: u16 },
-    #[serde(rename = "MAX_POSITION")]
-    #[serde(rename_all = "camelCase")]
-    MaxPosition { max_position: String },
-    #[serde(rename = "MARKET_LOT_SIZE")]
-    #[serde(rename_all = "camelCase")]
-    MarketLotSize {
-        #[serde(with = "string_or_decimal")]
-        min_qty: Decimal,
-        #[serde(with = "string_or_decimal")]
-        max_qty: Decimal,
-        #[serde(with = "string_or_decimal")]
-        step_size: Decimal,
-    },
-    #[serde(rename = "TRAILING_DELTA")]
-    #[serde(rename_all = "camelCase")]
-    TrailingData {
-        min_trailing_above_delta: Option<u16>,
-        max_trailing_above_delta: Option<u16>,
-        min_trailing_below_delta: Option<u16>,
-        max_trailing_below_delta: Option<u16>,
-    },
+/// Enums which Bybit encodes as integers.
+macro_rules! int_enum {
+    ($(#[$meta:meta])* pub enum $name:ident { $($(#[$vmeta:meta])* $variant:ident = $value:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)*
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u8(match self {
+                    $(Self::$variant => $value,)*
+                })
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                match u8::deserialize(deserializer)? {
+                    $($value => Ok(Self::$variant),)*
+                    v => Err(serde::de::Error::custom(format!(
+                        concat!("invalid ", stringify!($name), " {}"),
+                        v
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+int_enum! {
+    /// Identifies the position an order belongs to. Hedge mode has one position per side.
+    #[derive(Default)]
+    pub enum PositionIdx {
+        #[default]
+        OneWay = 0,
+        HedgeBuy = 1,
+        HedgeSell = 2,
+    }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub enum OrderStatus {
-    New,
-    PartiallyFilled,
-    Filled,
-    Cancelled,
-    PendingCancel,
-    Rejected,
-    Expired,
+int_enum! {
+    /// `Hedge` holds a buy and a sell position at the same time. Spot has no positions and
+    /// inverse only supports `OneWay`.
+    #[derive(Default)]
+    pub enum PositionMode {
+        #[default]
+        OneWay = 0,
+        Hedge = 3,
+    }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum ExecutionType {
-    /// The order has been accepted into the engine.
-    New,
-    /// The order has been canceled by the user.
-    Canceled,
-    /// Liquidation Execution (Futures only)
-    Calculated,
-    /// (currently unused)
-    Replaced,
-    /// The order has been rejected and was not processed (This message appears only with Cancel Replace Orders wherein the new order placement is rejected but the request to cancel request succeeds.)
-    Rejected,
-    /// Part of the order or all of the order's quantity has filled.
-    Trade,
-    /// The order was canceled according to the order type's rules (e.g. LIMIT FOK orders with no fill, LIMIT IOC or MARKET orders that partially fill) or by the exchange, (e.g. orders canceled during liquidation, orders canceled during maintenance).
-    Expired,
-    /// The order has expired due to STP trigger.
-    TradePrevention,
+int_enum! {
+    /// The margin mode of a position, for accounts not using unified margin.
+    #[derive(Default)]
+    pub enum TradeMode {
+        #[default]
+        Cross = 0,
+        Isolated = 1,
+    }
 }
 
-// {
-//       "rateLimitType": "ORDERS",
-//       "interval": "DAY",
-//       "limit": 100000
-//     }
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct RateLimit {
-    pub rate_limit_type: RateLimitType,
-    pub interval: Interval,
-    pub limit: u64,
+int_enum! {
+    /// Classic account or the version of the unified trading account (UTA).
+    pub enum UnifiedMarginStatus {
+        Classic = 1,
+        Uta1 = 3,
+        Uta1Pro = 4,
+        Uta2 = 5,
+        Uta2Pro = 6,
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum RateLimitType {
-    Orders,
-    RequestWeight,
+int_enum! {
+    /// The direction the price has to move to trigger a conditional order.
+    pub enum TriggerDirection {
+        Rise = 1,
+        Fall = 2,
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum Interval {
-    Second,
-    Minute,
-    Day,
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriggerBy {
+    LastPrice,
+    IndexPrice,
+    MarkPrice,
 }
 
-#[derive(Deserialize, Serialize, Debug, Default, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct AssetInformation {
-    asset: String,
-    margin_available: bool,
-    auto_asset_exchange: String,
+/// `Full` closes the whole position at market, `Partial` closes the given size and
+/// supports limit TP/SL orders.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TpSlMode {
+    #[default]
+    Full,
+    Partial,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum OrderBookType {
-    Snapshot,
-    Delta,
-}
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum AccountType {
-    Spot,
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum Permission {
-    Spot,
+/// Self match prevention
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SmpType {
+    None,
+    CancelMaker,
+    CancelTaker,
+    CancelBoth,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// The unit of `qty` of spot market orders.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
-pub struct AccountInformation {
-    pub maker_commission: f32,
-    pub taker_commission: f32,
-    pub buyer_commission: f32,
-    pub seller_commission: f32,
-    pub can_trade: bool,
-    pub can_withdraw: bool,
-    pub can_deposit: bool,
-    pub brokered: bool,
-    pub require_self_trade_prevention: bool,
-    pub update_time: u64,
-    pub account_type: AccountType,
-    pub permissions: Vec<Permission>,
-    pub balances: Vec<Balance>,
+pub enum MarketUnit {
+    BaseCoin,
+    QuoteCoin,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Balance {
-    pub asset: String,
-    #[serde(with = "string_or_decimal")]
-    pub free: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub locked: Decimal,
+/// The kind of spot orders. Derivatives only take `Order` and `StopOrder` on queries.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum OrderFilter {
+    Order,
+    tpslOrder,
+    StopOrder,
+    OcoOrder,
+    BidirectionalTpslOrder,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct Bids {
-    #[serde(with = "string_or_decimal")]
-    pub price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub qty: Decimal,
-
-    // Never serialized.
-    #[serde(skip_serializing, rename = "ignore")]
-    _ignore: Vec<String>,
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderStatus {
+    /// Accepted by the system but not yet by the matching engine
+    Created,
+    New,
+    Rejected,
+    PartiallyFilled,
+    /// Spot only
+    PartiallyFilledCanceled,
+    Filled,
+    Cancelled,
+    /// Conditional orders waiting for their trigger
+    Untriggered,
+    Triggered,
+    Deactivated,
+    /// A TP/SL or conditional order placed on the order book
+    Active,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct Asks {
-    #[serde(with = "string_or_decimal")]
-    pub price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub qty: Decimal,
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExecutionType {
+    Trade,
+    /// Auto-deleveraging
+    AdlTrade,
+    Funding,
+    /// Takeover by the liquidation engine
+    BustTrade,
+    /// Delivery of dated futures and options
+    Delivery,
+    /// Settlement of inverse futures
+    Settle,
+    BlockTrade,
+    MovePosition,
+    FutureSpread,
+}
+
+/// The products covered by disconnection protection.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DcpProduct {
+    #[default]
+    Options,
+    Derivatives,
+    Spot,
+}
 
-    // Never serialized.
-    #[serde(skip_serializing, rename = "ignore")]
-    _ignore: Vec<String>,
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum OrderBookType {
+    Snapshot,
+    Delta,
+}
+/// The V5 `accountType`. Unified trading accounts only have `Unified`, classic accounts have
+/// `Contract` for derivatives and `Spot`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountType {
+    #[default]
+    Unified,
+    Contract,
+    Spot,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -323,46 +305,6 @@ pub struct UserDataStream {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Success {}
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(untagged)]
-pub enum Prices {
-    AllPrices(Vec<SymbolPrice>),
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct SymbolPrice {
-    pub symbol: String,
-    #[serde(with = "string_or_decimal")]
-    pub price: Decimal,
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-#[serde(untagged)]
-pub enum BookTickers {
-    AllBookTickers(Vec<Ticker>),
-}
-
-#[derive(Debug, Clone)]
-pub enum KlineSummaries {
-    AllKlineSummaries(Vec<KlineSummary>),
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Ticker {
-    pub symbol: String,
-    #[serde(with = "string_or_decimal")]
-    pub bid_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub bid_qty: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub ask_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub ask_qty: Decimal,
-}
-
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TradeHistory {
@@ -382,149 +324,6 @@ pub struct TradeHistory {
     pub is_best_match: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct PriceStats {
-    pub symbol: String,
-    #[serde(with = "string_or_decimal")]
-    pub price_change: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub price_change_percent: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub weighted_avg_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub prev_close_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub last_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub bid_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub ask_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub open_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub high_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub low_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub volume: Decimal,
-    pub open_time: u64,
-    pub close_time: u64,
-    pub first_id: i64, // For dummy symbol "123456", it is -1
-    pub last_id: i64,  // Same as above
-    pub count: u64,
-}
-
-#[derive(Debug, Clone)]
-pub struct KlineSummary {
-    pub open_time: i64,
-
-    pub open: Decimal,
-
-    pub high: Decimal,
-
-    pub low: Decimal,
-
-    pub close: Decimal,
-
-    pub volume: Decimal,
-
-    pub close_time: i64,
-
-    pub quote_asset_volume: Decimal,
-
-    pub number_of_trades: i64,
-
-    pub taker_buy_base_asset_volume: Decimal,
-
-    pub taker_buy_quote_asset_volume: Decimal,
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Kline {
-    #[serde(rename = "t")]
-    pub start_time: i64,
-    #[serde(rename = "T")]
-    pub end_time: i64,
-    #[serde(rename = "s")]
-    pub symbol: String,
-    #[serde(rename = "i")]
-    pub interval: String,
-    #[serde(rename = "f")]
-    pub first_trade_id: i32,
-    #[serde(rename = "L")]
-    pub last_trade_id: i32,
-    #[serde(rename = "o")]
-    pub open: String,
-    #[serde(rename = "c")]
-    pub close: String,
-    #[serde(rename = "h")]
-    pub high: String,
-    #[serde(rename = "l")]
-    pub low: String,
-    #[serde(rename = "v")]
-    pub volume: String,
-    #[serde(rename = "n")]
-    pub number_of_trades: i32,
-    #[serde(rename = "x")]
-    pub is_final_bar: bool,
-    #[serde(rename = "q")]
-    pub quote_volume: String,
-    #[serde(rename = "V")]
-    pub active_buy_volume: String,
-    #[serde(rename = "Q")]
-    pub active_volume_buy_quote: String,
-    #[serde(skip_serializing, rename = "B")]
-    pub ignore_me: String,
-}
-
-// {
-//     "symbol": "ETHBTC",
-//     "status": "TRADING",
-//     "baseAsset": "ETH",
-//     "baseAssetPrecision": 8,
-//     "quoteAsset": "BTC",
-//     "quotePrecision": 8,
-//     "orderTypes": ["LIMIT", "MARKET"],
-//     "icebergAllowed": false,
-//     "filters": [{
-//       "filterType": "PRICE_FILTER",
-//       "minPrice": "0.00000100",
-//       "maxPrice": "100000.00000000",
-//       "tickSize": "0.00000100"
-//     }, {
-//       "filterType": "LOT_SIZE",
-//       "minQty": "0.00100000",
-//       "maxQty": "100000.00000000",
-//       "stepSize": "0.00100000"
-//     }, {
-//       "filterType": "MIN_NOTIONAL",
-//       "minNotional": "0.00100000"
-//     }]
-//   }
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum ExchangeFilter {
-    ExchangeMaxNumOrders { limit: u64 },
-    ExchangeMaxAlgoOrders { limit: u64 },
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct OrderBook {
-    pub last_update_id: u64,
-    pub bids: Vec<Bids>,
-    pub asks: Vec<Asks>,
-}
-
-#[derive(Serialize, Deserialize, Clone, Debug)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum NewOrderResponseType {
-    Ack,
-    Result,
-}
-
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderExecType {
@@ -536,84 +335,3 @@ pub enum OrderExecType {
 pub enum OrderRejectReason {
     None,
 }
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Asset {
-    pub asset: String,
-    #[serde(with = "string_or_decimal")]
-    pub wallet_balance: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub unrealized_profit: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub margin_balance: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub maint_margin: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub initial_margin: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub position_initial_margin: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub open_order_initial_margin: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub max_withdraw_amount: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub cross_wallet_balance: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub cross_un_pnl: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub available_balance: Decimal,
-    #[serde(with = "string_or")]
-    pub margin_available: bool,
-    pub update_time: u64,
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Position {
-    pub symbol: String,
-    #[serde(with = "string_or_decimal")]
-    pub initial_margin: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub maint_margin: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub unrealized_profit: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub position_initial_margin: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub open_order_initial_margin: Decimal,
-    pub leverage: String,
-    #[serde(with = "string_or")]
-    pub isolated: bool,
-    #[serde(with = "string_or_decimal")]
-    pub entry_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub max_notional: Decimal,
-    pub position_side: String,
-    #[serde(with = "string_or_decimal", rename = "positionAmt")]
-    pub position_amount: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub notional: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub isolated_wallet: Decimal,
-    pub update_time: u64,
-    #[serde(with = "string_or_decimal")]
-    pub bid_notional: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub ask_notional: Decimal,
-}
-
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum PositionSide {
-    Both,
-    Long,
-    Short,
-}
-
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum WorkingType {
-    MarkPrice,
-    ContractPrice,
-}
@@ -1,48 +1,19 @@
-use crate::parser::{string_or_decimal, string_or_decimal_opt};
+use crate::models::{
+    ExecutionType, MarketUnit, OrderStatus, OrderType, PositionIdx, Product, Side, SmpType,
+    TimeInForce, TpSlMode, TriggerBy, TriggerDirection,
+};
+use crate::parser::{
+    empty_as_none, string_or_decimal, string_or_decimal_opt, string_or_ts_milliseconds,
+    string_or_ts_milliseconds_opt,
+};
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::de::IntoDeserializer;
+use serde::{Deserialize, Deserializer, Serialize};
 
 /* -------------------------------------------------------------------------- */
 /*                                   Structs                                  */
 /* -------------------------------------------------------------------------- */
-pub type NewOrderResponse = NewOrder;
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct NewOrder {
-    pub client_order_id: String,
-    #[serde(with = "string_or_decimal")]
-    pub cum_qty: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub cum_quote: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub executed_qty: Decimal,
-    pub order_id: u64,
-    #[serde(with = "string_or_decimal")]
-    pub avg_price: Decimal,
-    #[serde(with = "string_or_decimal")]
-    pub orig_qty: Decimal,
-    pub reduce_only: bool,
-    pub side: String,
-    pub position_side: String,
-    pub status: String,
-    #[serde(with = "string_or_decimal")]
-    pub stop_price: Decimal,
-    pub close_position: bool,
-    pub symbol: String,
-    pub time_in_force: String,
-    #[serde(rename = "type")]
-    pub type_name: String,
-    pub orig_type: String,
-    #[serde(default, with = "string_or_decimal_opt")]
-    pub activate_price: Option<Decimal>,
-    #[serde(default, with = "string_or_decimal_opt")]
-    pub price_rate: Option<Decimal>,
-    pub update_time: u64,
-    pub working_type: String,
-    price_protect: bool,
-}
-
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderInfo {
@@ -86,40 +57,188 @@ pub struct FillInfo {
     pub trade_id: Option<u64>,
 }
 
-pub type CancelOrderResponse = CanceledOrder;
+/// The ids of an order created, amended or cancelled.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderIds {
+    pub order_id: String,
+    pub order_link_id: String,
+}
 
+/// An item of a batch create, amend or cancel response. The ids are empty if the item failed.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct CanceledOrder {
-    pub client_order_id: String,
+pub struct BatchOrder {
+    pub category: Product,
+    pub symbol: String,
+    pub order_id: String,
+    pub order_link_id: String,
+    /// Batch create only
+    #[serde(default, with = "string_or_ts_milliseconds_opt")]
+    pub create_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BatchOrders {
+    pub list: Vec<BatchOrder>,
+}
+
+/// A V5 order, as returned by the order queries and pushed by the `order` websocket topic.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    /// Only pushed by the websocket, REST responses carry the category next to the list
+    #[serde(default)]
+    pub category: Option<Product>,
+    pub symbol: String,
+    pub order_id: String,
+    pub order_link_id: String,
+    #[serde(default)]
+    pub block_trade_id: String,
+    pub side: Side,
+    pub position_idx: PositionIdx,
+    pub order_status: OrderStatus,
+    #[serde(default)]
+    pub create_type: String,
+    pub cancel_type: String,
+    pub reject_reason: String,
+    pub time_in_force: TimeInForce,
+    /// Spot only, "1" for margin orders
+    #[serde(default)]
+    pub is_leverage: String,
     #[serde(with = "string_or_decimal")]
-    pub cum_qty: Decimal,
+    pub price: Decimal,
     #[serde(with = "string_or_decimal")]
-    pub cum_quote: Decimal,
+    pub qty: Decimal,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub avg_price: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub leaves_qty: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub leaves_value: Option<Decimal>,
     #[serde(with = "string_or_decimal")]
-    pub executed_qty: Decimal,
-    pub order_id: u64,
+    pub cum_exec_qty: Decimal,
     #[serde(with = "string_or_decimal")]
-    pub orig_qty: Decimal,
-    pub orig_type: String,
+    pub cum_exec_value: Decimal,
     #[serde(with = "string_or_decimal")]
-    pub price: Decimal,
+    pub cum_exec_fee: Decimal,
+    pub order_type: OrderType,
+    #[serde(default, with = "empty_as_none")]
+    pub stop_order_type: Option<OrderType>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub order_iv: Option<Decimal>,
+    #[serde(default, with = "empty_as_none")]
+    pub market_unit: Option<MarketUnit>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub trigger_price: Option<Decimal>,
+    #[serde(default, with = "empty_as_none")]
+    pub trigger_by: Option<TriggerBy>,
+    #[serde(default, deserialize_with = "trigger_direction")]
+    pub trigger_direction: Option<TriggerDirection>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub take_profit: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub stop_loss: Option<Decimal>,
+    #[serde(default, with = "empty_as_none")]
+    pub tpsl_mode: Option<TpSlMode>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub tp_limit_price: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub sl_limit_price: Option<Decimal>,
+    #[serde(default, with = "empty_as_none")]
+    pub tp_trigger_by: Option<TriggerBy>,
+    #[serde(default, with = "empty_as_none")]
+    pub sl_trigger_by: Option<TriggerBy>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub last_price_on_created: Option<Decimal>,
     pub reduce_only: bool,
-    pub side: String,
-    pub position_side: String,
-    pub status: String,
-    #[serde(with = "string_or_decimal")]
-    pub stop_price: Decimal,
-    pub close_position: bool,
+    pub close_on_trigger: bool,
+    #[serde(default)]
+    pub place_type: String,
+    #[serde(default, with = "empty_as_none")]
+    pub smp_type: Option<SmpType>,
+    #[serde(default)]
+    pub smp_group: u64,
+    #[serde(default)]
+    pub smp_order_id: String,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub created_time: DateTime<Utc>,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub updated_time: DateTime<Utc>,
+    /// Websocket only, the coin spot fees are charged in
+    #[serde(default)]
+    pub fee_currency: String,
+}
+
+// 0 for non-conditional orders
+fn trigger_direction<'de, D>(deserializer: D) -> Result<Option<TriggerDirection>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match u8::deserialize(deserializer)? {
+        0 => Ok(None),
+        v => TriggerDirection::deserialize(v.into_deserializer()).map(Some),
+    }
+}
+
+// https://bybit-exchange.github.io/docs/v5/order/execution
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Execution {
     pub symbol: String,
-    pub time_in_force: String,
-    #[serde(rename = "type")]
-    pub type_name: String,
+    pub order_id: String,
+    pub order_link_id: String,
+    pub side: Side,
+    /// `None` for executions without a user order, e.g. funding, settlement and ADL
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub order_price: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub order_qty: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub leaves_qty: Option<Decimal>,
+    #[serde(default)]
+    pub create_type: String,
+    #[serde(default, with = "empty_as_none")]
+    pub order_type: Option<OrderType>,
+    #[serde(default, with = "empty_as_none")]
+    pub stop_order_type: Option<OrderType>,
+    pub exec_id: String,
+    #[serde(with = "string_or_decimal")]
+    pub exec_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub exec_qty: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub exec_value: Decimal,
+    pub exec_type: ExecutionType,
+    /// Negative for rebates
+    #[serde(with = "string_or_decimal")]
+    pub exec_fee: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub fee_rate: Decimal,
+    /// Spot only, other categories are charged in the settle coin
+    #[serde(default)]
+    pub fee_currency: String,
+    pub is_maker: bool,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub exec_time: DateTime<Utc>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub mark_price: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub index_price: Option<Decimal>,
+    /// Option only
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub underlying_price: Option<Decimal>,
+    /// Option only
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub trade_iv: Option<Decimal>,
+    /// Option only
     #[serde(default, with = "string_or_decimal_opt")]
-    pub activate_price: Option<Decimal>,
+    pub mark_iv: Option<Decimal>,
+    /// The position size closed by this execution
     #[serde(default, with = "string_or_decimal_opt")]
-    pub price_rate: Option<Decimal>,
-    pub update_time: u64,
-    pub working_type: String,
-    price_protect: bool,
+    pub closed_size: Option<Decimal>,
+    #[serde(default)]
+    pub block_trade_id: String,
+    #[serde(default)]
+    pub seq: u64,
 }
@@ -0,0 +1,176 @@
+use crate::models::{ExecutionType, OrderType, PositionIdx, Product, Side, TpSlMode, TradeMode};
+use crate::parser::{
+    empty_as_none, string_or_decimal, string_or_decimal_opt, string_or_ts_milliseconds,
+    string_or_ts_milliseconds_opt,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, BoolFromInt};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PositionStatus {
+    Normal,
+    /// In liquidation
+    Liq,
+    /// In auto-deleveraging
+    Adl,
+}
+
+// https://bybit-exchange.github.io/docs/v5/position
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub position_idx: PositionIdx,
+    #[serde(default)]
+    pub risk_id: u64,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub risk_limit_value: Option<Decimal>,
+    pub symbol: String,
+    /// `None` for an empty one-way position
+    #[serde(default, with = "empty_as_none")]
+    pub side: Option<Side>,
+    #[serde(with = "string_or_decimal")]
+    pub size: Decimal,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub avg_price: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub position_value: Option<Decimal>,
+    #[serde(default)]
+    pub trade_mode: Option<TradeMode>,
+    #[serde_as(as = "BoolFromInt")]
+    pub auto_add_margin: bool,
+    pub position_status: PositionStatus,
+    /// Empty for portfolio margin accounts
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub leverage: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub mark_price: Option<Decimal>,
+    /// `None` when the position cannot be liquidated, e.g. with unified margin
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub liq_price: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub bust_price: Option<Decimal>,
+    #[serde(default, rename = "positionIM", with = "string_or_decimal_opt")]
+    pub position_im: Option<Decimal>,
+    #[serde(default, rename = "positionMM", with = "string_or_decimal_opt")]
+    pub position_mm: Option<Decimal>,
+    #[serde(default, with = "empty_as_none")]
+    pub tpsl_mode: Option<TpSlMode>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub take_profit: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub stop_loss: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub trailing_stop: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub unrealised_pnl: Option<Decimal>,
+    /// Realised PnL of the current holding
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub cur_realised_pnl: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub cum_realised_pnl: Option<Decimal>,
+    /// Auto-deleveraging rank, from 0 (empty position) to 5 (first to be deleveraged)
+    #[serde(default)]
+    pub adl_rank_indicator: u8,
+    #[serde(default)]
+    pub is_reduce_only: bool,
+    #[serde(default, with = "string_or_ts_milliseconds_opt")]
+    pub created_time: Option<DateTime<Utc>>,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub updated_time: DateTime<Utc>,
+    #[serde(default)]
+    pub seq: i64,
+}
+
+// https://bybit-exchange.github.io/docs/v5/position/close-pnl
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnl {
+    pub symbol: String,
+    pub order_id: String,
+    /// The side of the closing order
+    pub side: Side,
+    #[serde(with = "string_or_decimal")]
+    pub qty: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub order_price: Decimal,
+    pub order_type: OrderType,
+    pub exec_type: ExecutionType,
+    #[serde(with = "string_or_decimal")]
+    pub closed_size: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub cum_entry_value: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub avg_entry_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub cum_exit_value: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub avg_exit_price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub closed_pnl: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub fill_count: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub leverage: Decimal,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub open_fee: Option<Decimal>,
+    #[serde(default, with = "string_or_decimal_opt")]
+    pub close_fee: Option<Decimal>,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub created_time: DateTime<Utc>,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub updated_time: DateTime<Utc>,
+}
+
+/// A position moved by `MovePositionsRequest`, at `price` from the source account's point of view.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MovePosition {
+    pub category: Product,
+    pub symbol: String,
+    pub price: Decimal,
+    pub side: Side,
+    pub qty: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MovePositionStatus {
+    #[default]
+    Processing,
+    Filled,
+    Rejected,
+}
+
+// https://bybit-exchange.github.io/docs/v5/position/move-position-history
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MovePositionRecord {
+    pub block_trade_id: String,
+    pub category: Product,
+    pub order_id: String,
+    pub user_id: u64,
+    pub symbol: String,
+    pub side: Side,
+    #[serde(with = "string_or_decimal")]
+    pub price: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub qty: Decimal,
+    #[serde(with = "string_or_decimal")]
+    pub exec_fee: Decimal,
+    pub status: MovePositionStatus,
+    #[serde(default)]
+    pub exec_id: String,
+    #[serde(default)]
+    pub result_code: i64,
+    #[serde(default)]
+    pub result_message: String,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "string_or_ts_milliseconds")]
+    pub updated_at: DateTime<Utc>,
+    /// "Taker" or "Maker" when rejected
+    #[serde(default)]
+    pub reject_party: String,
+}
@@ -37,7 +37,7 @@ pub mod string_or_decimal {
 
 pub mod string_or_decimal_opt {
     use rust_decimal::Decimal;
-    use serde::{Deserializer, Serializer};
+    use serde::{de, Deserialize, Deserializer, Serializer};
     use std::fmt;
 
     pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
@@ -51,27 +51,69 @@ pub mod string_or_decimal_opt {
         }
     }
 
+    // Bybit uses "" for absent values
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
     where
         D: Deserializer<'de>,
     {
-        Ok(Some(super::string_or_decimal::deserialize(deserializer)?))
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrFloat {
+            String(String),
+            Float(Decimal),
+        }
+
+        match Option::<StringOrFloat>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(StringOrFloat::String(s)) if s.is_empty() => Ok(None),
+            Some(StringOrFloat::String(s)) if s == "INF" => Ok(Some(Decimal::MAX)),
+            Some(StringOrFloat::String(s)) => s.parse().map(Some).map_err(de::Error::custom),
+            Some(StringOrFloat::Float(i)) => Ok(Some(i)),
+        }
     }
 }
 
 pub mod string_or {
+    use serde::{de, Deserialize, Deserializer};
+    use std::{fmt, str::FromStr};
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr + Deserialize<'de>,
+        T::Err: fmt::Display,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOr<T> {
+            String(String),
+            T(T),
+        }
+
+        match StringOr::deserialize(deserializer)? {
+            StringOr::String(s) => s.parse().map_err(de::Error::custom),
+            StringOr::T(t) => Ok(t),
+        }
+    }
+}
+
+pub mod string_or_opt {
     use serde::{de, Deserialize, Deserializer, Serializer};
     use std::{fmt, str::FromStr};
 
-    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
     where
         T: fmt::Display,
         S: Serializer,
     {
-        serializer.collect_str(value)
+        match value {
+            Some(v) => serializer.collect_str(v),
+            None => serializer.serialize_none(),
+        }
     }
 
-    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    // Bybit uses "" for absent values
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
     where
         D: Deserializer<'de>,
         T: FromStr + Deserialize<'de>,
@@ -84,9 +126,93 @@ pub mod string_or {
             T(T),
         }
 
-        match StringOr::deserialize(deserializer)? {
-            StringOr::String(s) => s.parse().map_err(de::Error::custom),
-            StringOr::T(t) => Ok(t),
+        match Option::<StringOr<T>>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(StringOr::String(s)) if s.is_empty() => Ok(None),
+            Some(StringOr::String(s)) => s.parse().map(Some).map_err(de::Error::custom),
+            Some(StringOr::T(t)) => Ok(Some(t)),
+        }
+    }
+}
+
+/// Millisecond timestamps, which V5 sends as strings most of the time.
+pub mod string_or_ts_milliseconds {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde::{de, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&value.timestamp_millis())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ms: i64 = super::string_or::deserialize(deserializer)?;
+        Utc.timestamp_millis_opt(ms)
+            .single()
+            .ok_or_else(|| de::Error::custom(format!("invalid timestamp {ms}")))
+    }
+}
+
+/// Optional millisecond timestamps, which V5 sends as strings most of the time.
+/// "" and "0" (e.g. the delivery time of perpetuals) are `None`.
+pub mod string_or_ts_milliseconds_opt {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde::{de, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(v) => super::string_or_ts_milliseconds::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match super::string_or_opt::deserialize::<_, i64>(deserializer)? {
+            None | Some(0) => Ok(None),
+            Some(ms) => Utc
+                .timestamp_millis_opt(ms)
+                .single()
+                .map(Some)
+                .ok_or_else(|| de::Error::custom(format!("invalid timestamp {ms}"))),
+        }
+    }
+}
+
+/// Optional enums, which V5 leaves as "" or "UNKNOWN" when absent.
+pub mod empty_as_none {
+    use serde::de::{value::StringDeserializer, DeserializeOwned, Error};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: DeserializeOwned,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(s) if s.is_empty() || s == "UNKNOWN" => Ok(None),
+            Some(s) => T::deserialize(StringDeserializer::<serde::de::value::Error>::new(s))
+                .map(Some)
+                .map_err(D::Error::custom),
         }
     }
 }
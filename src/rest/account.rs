@@ -1,63 +1,190 @@
-use crate::models::Product;
-use crate::models::{Asset, Position};
-use crate::parser::string_or;
-use crate::parser::string_or_decimal;
+use crate::models::account::{
+    AccountInfo, CoinGreeks, CollateralInfo, CollateralSwitch, FeeRate, WalletBalance,
+};
+use crate::models::{AccountType, Product};
 use reqwest::Method;
-use rust_decimal::Decimal;
 
 crate::define_request! {
-    Name => GetCurrentPositionMode;
-    Product => Product::UsdMFutures;
+    Name => WalletBalance;
     Method => Method::GET;
-    Endpoint => "/fapi/v1/positionSide/dual";
+    Endpoint => "/v5/account/wallet-balance";
     Signed => true;
-    Request => {};
+    Request => {
+        pub account_type: AccountType,
+        /// Comma separated coins, e.g. "USDT,USDC"
+        pub coin: Option<String>,
+    };
     Response => {
-        pub dual_side_position: bool,
+        pub list: Vec<WalletBalance>,
     };
 }
 
 crate::define_request! {
-    Name => AccountInformationV2;
-    Product => Product::UsdMFutures;
+    Name => AccountInfo;
     Method => Method::GET;
-    Endpoint => "/fapi/v2/account";
+    Endpoint => "/v5/account/info";
     Signed => true;
     Request => {};
+    Response => AccountInfo;
+}
+
+// The fees of the account, which depend on its VIP level
+crate::define_request! {
+    Name => FeeRate;
+    Method => Method::GET;
+    Endpoint => "/v5/account/fee-rate";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        /// Spot, linear and inverse only
+        pub symbol: Option<String>,
+        /// Option only
+        pub base_coin: Option<String>,
+    };
     Response => {
-        #[serde(with = "string_or_decimal")]
-        pub fee_tier: Decimal,
-        #[serde(with = "string_or")]
-        pub can_trade: bool,
-        #[serde(with = "string_or")]
-        pub can_deposit: bool,
-        #[serde(with = "string_or")]
-        pub can_withdraw: bool,
-        #[serde(with = "string_or_decimal")]
-        pub update_time: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub total_initial_margin: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub total_maint_margin: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub total_wallet_balance: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub total_unrealized_profit: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub total_margin_balance: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub total_position_initial_margin: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub total_open_order_initial_margin: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub total_cross_wallet_balance: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub total_cross_un_pnl: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub available_balance: Decimal,
-        #[serde(with = "string_or_decimal")]
-        pub max_withdraw_amount: Decimal,
-        pub assets: Vec<Asset>,
-        pub positions: Vec<Position>,
+        pub list: Vec<FeeRate>,
+    };
+}
+
+crate::define_request! {
+    Name => CollateralInfo;
+    Method => Method::GET;
+    Endpoint => "/v5/account/collateral-info";
+    Signed => true;
+    Request => {
+        pub currency: Option<String>,
+    };
+    Response => {
+        pub list: Vec<CollateralInfo>,
+    };
+}
+
+// Unified accounts only. Only coins with `margin_collateral` can be switched on.
+crate::define_request! {
+    Name => SetCollateralSwitch;
+    Method => Method::POST;
+    Endpoint => "/v5/account/set-collateral-switch";
+    Signed => true;
+    Request => {
+        pub coin: String,
+        pub collateral_switch: CollateralSwitch,
     };
+    Response => {};
+}
+
+// The greeks of the option positions of every base coin, or of `base_coin` only
+crate::define_request! {
+    Name => CoinGreeks;
+    Method => Method::GET;
+    Endpoint => "/v5/asset/coin-greeks";
+    Signed => true;
+    Request => {
+        pub base_coin: Option<String>,
+    };
+    Response => {
+        pub list: Vec<CoinGreeks>,
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        CoinGreeksResponse, CollateralInfoResponse, FeeRateResponse, SetCollateralSwitchRequest,
+        WalletBalanceRequest, WalletBalanceResponse,
+    };
+    use crate::models::account::{AccountInfo, CollateralSwitch, MarginMode};
+    use crate::models::{AccountType, UnifiedMarginStatus};
+    use crate::rest::Request;
+    use anyhow::Error;
+    use fehler::throws;
+    use serde_json::from_str;
+
+    #[throws(Error)]
+    #[test]
+    fn wallet_balance() {
+        let query = serde_qs::to_string(&WalletBalanceRequest {
+            account_type: AccountType::Contract,
+            coin: Some("BTC,USDT".into()),
+        })?;
+        assert_eq!(query, "accountType=CONTRACT&coin=BTC%2CUSDT");
+
+        let resp: WalletBalanceResponse = from_str(
+            r#"{"list":[{"totalEquity":"3.31216591","accountIMRate":"0","totalMarginBalance":"3.00326056","totalInitialMargin":"0","accountType":"UNIFIED","totalAvailableBalance":"3.00326056","accountMMRate":"0.0012","totalPerpUPL":"12.5","totalWalletBalance":"3.00326056","accountLTV":"0.05","totalMaintenanceMargin":"0","coin":[{"availableToBorrow":"3","bonus":"0","accruedInterest":"0","availableToWithdraw":"0","totalOrderIM":"0.1","equity":"0","totalPositionMM":"0.3","usdValue":"0","spotHedgingQty":"0.01592413","unrealisedPnl":"0","collateralSwitch":true,"borrowAmount":"0.0","totalPositionIM":"0.2","walletBalance":"0","cumRealisedPnl":"0","locked":"0","marginCollateral":true,"coin":"BTC"}]}]}"#,
+        )?;
+        let account = &resp.list[0];
+        assert_eq!(account.account_type, AccountType::Unified);
+        assert_eq!(account.total_equity, Some("3.31216591".parse()?));
+        // The acronym fields are not camelCase
+        assert_eq!(account.account_mm_rate, Some("0.0012".parse()?));
+        assert_eq!(account.account_ltv, Some("0.05".parse()?));
+        assert_eq!(account.total_perp_upl, Some("12.5".parse()?));
+        let btc = &account.coin[0];
+        assert!(btc.collateral_switch);
+        assert_eq!(btc.borrow_amount, Some("0".parse()?));
+        assert_eq!(btc.free, None);
+        assert_eq!(btc.total_order_im, Some("0.1".parse()?));
+        assert_eq!(btc.total_position_im, Some("0.2".parse()?));
+        assert_eq!(btc.total_position_mm, Some("0.3".parse()?));
+
+        let resp: WalletBalanceResponse = from_str(
+            r#"{"list":[{"totalEquity":"","accountIMRate":"","totalMarginBalance":"","totalInitialMargin":"","accountType":"CONTRACT","totalAvailableBalance":"","accountMMRate":"","totalPerpUPL":"","totalWalletBalance":"","accountLTV":"","totalMaintenanceMargin":"","coin":[{"availableToBorrow":"","bonus":"0","accruedInterest":"","availableToWithdraw":"9.5","totalOrderIM":"0","equity":"10.01","totalPositionMM":"0","usdValue":"","unrealisedPnl":"0","collateralSwitch":false,"borrowAmount":"","totalPositionIM":"0","walletBalance":"10.01","cumRealisedPnl":"0.01","locked":"","marginCollateral":false,"coin":"USDT"}]}]}"#,
+        )?;
+        assert_eq!(resp.list[0].total_equity, None);
+        assert_eq!(resp.list[0].coin[0].equity, "10.01".parse()?);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn account_info() {
+        let info: AccountInfo = from_str(
+            r#"{"marginMode":"REGULAR_MARGIN","updatedTime":"1697078946000","unifiedMarginStatus":5,"dcpStatus":"OFF","timeWindow":10,"smpGroup":0,"isMasterTrader":false,"spotHedgingStatus":"OFF"}"#,
+        )?;
+        assert_eq!(info.margin_mode, MarginMode::RegularMargin);
+        assert_eq!(info.unified_margin_status, UnifiedMarginStatus::Uta2);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn fee_rate() {
+        let resp: FeeRateResponse = from_str(
+            r#"{"list":[{"symbol":"ETHUSDT","takerFeeRate":"0.0006","makerFeeRate":"-0.0001"}]}"#,
+        )?;
+        assert_eq!(resp.list[0].taker_fee_rate, "0.0006".parse()?);
+        assert_eq!(resp.list[0].maker_fee_rate, "-0.0001".parse()?);
+
+        let resp: FeeRateResponse = from_str(
+            r#"{"list":[{"baseCoin":"SOL","takerFeeRate":"0.0003","makerFeeRate":"0.0003"}]}"#,
+        )?;
+        assert_eq!(resp.list[0].base_coin, "SOL");
+        assert!(resp.list[0].symbol.is_empty());
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn collateral() {
+        let resp: CollateralInfoResponse = from_str(
+            r#"{"list":[{"availableToBorrow":"3","freeBorrowingAmount":"","freeBorrowAmount":"0","maxBorrowingAmount":"3","hourlyBorrowRate":"0.00000147","borrowUsageRate":"0","collateralSwitch":true,"borrowAmount":"0","borrowable":true,"currency":"BTC","otherBorrowAmount":"0","marginCollateral":true,"freeBorrowingLimit":"0","collateralRatio":"0.95"}]}"#,
+        )?;
+        let btc = &resp.list[0];
+        assert!(btc.collateral_switch && btc.margin_collateral);
+        assert_eq!(btc.hourly_borrow_rate, Some("0.00000147".parse()?));
+
+        let req = SetCollateralSwitchRequest {
+            coin: "BTC".into(),
+            collateral_switch: CollateralSwitch::Off,
+        };
+        assert_eq!(
+            SetCollateralSwitchRequest::BODY.encode(&req)?,
+            r#"{"coin":"BTC","collateralSwitch":"OFF"}"#
+        );
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn coin_greeks() {
+        let resp: CoinGreeksResponse = from_str(
+            r#"{"list":[{"baseCoin":"BTC","totalDelta":"0.00004001","totalGamma":"-0.00000009","totalVega":"-0.00039689","totalTheta":"0.01243824"}]}"#,
+        )?;
+        assert_eq!(resp.list[0].total_gamma, "-0.00000009".parse()?);
+    }
 }
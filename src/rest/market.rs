@@ -1,63 +1,659 @@
-use crate::models::{AssetInformation, Filter, Product, RateLimit};
-use crate::parser::string_or_decimal;
-use chrono::serde::{ts_milliseconds, ts_milliseconds_option};
-use chrono::{DateTime, Utc};
+use crate::models::market::{
+    AccountRatio, DeliveryPrice, FundingRate, HistoricalVolatility, Instrument, InstrumentStatus,
+    Insurance, IntervalTime, Kline, KlineInterval, OpenInterest, OptionsType, OrderBook,
+    PriceKline, PublicTrade, RiskLimit, Tickers,
+};
+use crate::models::{Page, Product};
+use crate::parser::string_or_ts_milliseconds;
+use crate::rest::split_range;
+use crate::{Bybit, BybitError};
+use chrono::serde::ts_milliseconds_option;
+use chrono::{DateTime, Duration, Utc};
+use futures::{future, stream, Stream, StreamExt, TryStreamExt};
 use reqwest::Method;
-use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use std::ops::Range;
 
+// Spot returns every instrument in one page, the other categories page by `cursor`.
+// Use `Bybit::paginate` to fetch the complete list.
 crate::define_request! {
-    Name => ExchangeInformation;
-    Product => Product::UsdMFutures;
+    Name => InstrumentsInfo;
     Method => Method::GET;
-    Endpoint => "/fapi/v1/exchangeInfo";
+    Endpoint => "/v5/market/instruments-info";
     Signed => false;
-    Request => {};
+    Request => {
+        pub category: Product,
+        pub symbol: Option<String>,
+        pub status: Option<InstrumentStatus>,
+        pub base_coin: Option<String>,
+        pub limit: Option<u64>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<Instrument>;
+}
+
+crate::impl_cursor_request!(InstrumentsInfoRequest => Instrument);
+
+// The kline endpoints return at most 1000 candles per request, newest first.
+crate::define_request! {
+    Name => Kline;
+    Method => Method::GET;
+    Endpoint => "/v5/market/kline";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub interval: KlineInterval,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end: Option<DateTime<Utc>>,
+        pub limit: Option<u64>,
+    };
+    Response => {
+        pub category: Product,
+        pub symbol: String,
+        pub list: Vec<Kline>,
+    };
+}
+
+crate::define_request! {
+    Name => MarkPriceKline;
+    Method => Method::GET;
+    Endpoint => "/v5/market/mark-price-kline";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub interval: KlineInterval,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end: Option<DateTime<Utc>>,
+        pub limit: Option<u64>,
+    };
+    Response => {
+        pub category: Product,
+        pub symbol: String,
+        pub list: Vec<PriceKline>,
+    };
+}
+
+crate::define_request! {
+    Name => IndexPriceKline;
+    Method => Method::GET;
+    Endpoint => "/v5/market/index-price-kline";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub interval: KlineInterval,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end: Option<DateTime<Utc>>,
+        pub limit: Option<u64>,
+    };
+    Response => {
+        pub category: Product,
+        pub symbol: String,
+        pub list: Vec<PriceKline>,
+    };
+}
+
+// Linear only
+crate::define_request! {
+    Name => PremiumIndexPriceKline;
+    Method => Method::GET;
+    Endpoint => "/v5/market/premium-index-price-kline";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub interval: KlineInterval,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end: Option<DateTime<Utc>>,
+        pub limit: Option<u64>,
+    };
     Response => {
-        pub timezone: String,
-        pub futures_type: String,
-        pub rate_limits: Vec<RateLimit>,
-        pub server_time: u64,
-        pub assets: Vec<AssetInformation>,
-        pub symbols: Vec<Symbol>
+        pub category: Product,
+        pub symbol: String,
+        pub list: Vec<PriceKline>,
     };
 }
 
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Symbol {
-    pub symbol: String,
-    pub pair: String,
-    pub contract_type: String,
-    pub status: String,
-    pub base_asset: String,
-    pub quote_asset: String,
-    pub filters: Vec<Filter>,
+/// The maximum number of candles returned by one kline request.
+pub const KLINE_LIMIT: u64 = 1000;
+
+impl Bybit {
+    /// Streams every candle starting within `range`, oldest first.
+    ///
+    /// The range is split into windows of `KLINE_LIMIT` candles, which are requested with at most
+    /// `concurrency` requests in flight. Candles returned by two windows are only yielded once.
+    pub fn kline_backfill(
+        &self,
+        category: Product,
+        symbol: &str,
+        interval: KlineInterval,
+        range: Range<DateTime<Utc>>,
+        concurrency: usize,
+    ) -> impl Stream<Item = Result<Kline, BybitError>> + '_ {
+        let windows = split_range(range.clone(), interval.duration() * KLINE_LIMIT as i32);
+
+        let symbol = symbol.to_string();
+        let mut last = None;
+        stream::iter(windows)
+            .map(move |(start, end)| {
+                self.request_owned(KlineRequest {
+                    category,
+                    symbol: symbol.clone(),
+                    interval,
+                    start: Some(start),
+                    end: Some(end),
+                    limit: Some(KLINE_LIMIT),
+                })
+            })
+            .buffered(concurrency.max(1))
+            .map_ok(|resp| {
+                // Bybit returns the newest candle first
+                stream::iter(resp.list.into_iter().rev().map(Ok))
+            })
+            .try_flatten()
+            .try_filter(move |kline| {
+                let keep = range.contains(&kline.start_time)
+                    && last.is_none_or(|last| kline.start_time > last);
+                if keep {
+                    last = Some(kline.start_time);
+                }
+                future::ready(keep)
+            })
+    }
+}
+
+// Limits: spot [1, 200], linear & inverse [1, 500], option [1, 25]
+crate::define_request! {
+    Name => OrderBook;
+    Method => Method::GET;
+    Endpoint => "/v5/market/orderbook";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub limit: Option<u64>,
+    };
+    Response => OrderBook;
 }
 
+// For options, either `symbol` or `base_coin` is required.
 crate::define_request! {
-    Name => FundingRate;
-    Product => Product::UsdMFutures;
+    Name => Tickers;
     Method => Method::GET;
-    Endpoint => "/fapi/v1/fundingRate";
+    Endpoint => "/v5/market/tickers";
     Signed => false;
     Request => {
+        pub category: Product,
         pub symbol: Option<String>,
+        pub base_coin: Option<String>,
+        /// Option only, e.g. "25DEC22"
+        pub exp_date: Option<String>,
+    };
+    Response => Tickers;
+}
+
+crate::define_request! {
+    Name => RecentTrade;
+    Method => Method::GET;
+    Endpoint => "/v5/market/recent-trade";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: Option<String>,
+        pub base_coin: Option<String>,
+        pub option_type: Option<OptionsType>,
+        pub limit: Option<u64>,
+    };
+    Response => {
+        pub category: Product,
+        pub list: Vec<PublicTrade>,
+    };
+}
+
+// Rows are returned newest first. Passing only `start_time` is rejected, use
+// `Bybit::funding_history` to walk back through the complete history.
+crate::define_request! {
+    Name => FundingHistory;
+    Method => Method::GET;
+    Endpoint => "/v5/market/funding/history";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
         #[serde(with = "ts_milliseconds_option")]
         pub start_time: Option<DateTime<Utc>>,
         #[serde(with = "ts_milliseconds_option")]
         pub end_time: Option<DateTime<Utc>>,
         pub limit: Option<u64>,
     };
-    Response => Vec<FundingRate>;
+    Response => {
+        pub category: Product,
+        pub list: Vec<FundingRate>,
+    };
 }
 
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct FundingRate {
-    pub symbol: String,
-    #[serde(with = "string_or_decimal")]
-    pub funding_rate: Decimal,
-    #[serde(with = "ts_milliseconds")]
-    pub funding_time: DateTime<Utc>,
+pub const FUNDING_HISTORY_LIMIT: u64 = 200;
+
+impl Bybit {
+    /// Streams the funding rates of `symbol` settled in `[start, end]`, newest first.
+    ///
+    /// `end` defaults to now and `start` to the launch of the contract. Each request asks for
+    /// `FUNDING_HISTORY_LIMIT` rows ending just before the oldest row of the previous one.
+    pub fn funding_history(
+        &self,
+        category: Product,
+        symbol: &str,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> impl Stream<Item = Result<FundingRate, BybitError>> + '_ {
+        let symbol = symbol.to_string();
+        stream::try_unfold(Some(end.unwrap_or_else(Utc::now)), move |end| {
+            let symbol = symbol.clone();
+            async move {
+                let end = match end {
+                    Some(end) => end,
+                    None => return Ok::<_, BybitError>(None),
+                };
+                let resp = self
+                    .request_owned(FundingHistoryRequest {
+                        category,
+                        symbol,
+                        start_time: start,
+                        end_time: Some(end),
+                        limit: Some(FUNDING_HISTORY_LIMIT),
+                    })
+                    .await?;
+                let next = match resp.list.last() {
+                    Some(oldest)
+                        if resp.list.len() as u64 >= FUNDING_HISTORY_LIMIT
+                            && start.is_none_or(|start| oldest.funding_rate_timestamp > start) =>
+                    {
+                        Some(oldest.funding_rate_timestamp - Duration::milliseconds(1))
+                    }
+                    _ => None,
+                };
+                let rows = resp.list.into_iter().filter(move |rate| {
+                    start.is_none_or(|start| rate.funding_rate_timestamp >= start)
+                        && rate.funding_rate_timestamp <= end
+                });
+                Ok(Some((stream::iter(rows.map(Ok::<_, BybitError>)), next)))
+            }
+        })
+        .try_flatten()
+    }
+}
+
+// Linear and inverse only, newest first. Use `Bybit::paginate` to fetch every page.
+crate::define_request! {
+    Name => OpenInterest;
+    Method => Method::GET;
+    Endpoint => "/v5/market/open-interest";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub interval_time: IntervalTime,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start_time: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end_time: Option<DateTime<Utc>>,
+        pub limit: Option<u64>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<OpenInterest>;
+}
+
+crate::impl_cursor_request!(OpenInterestRequest => OpenInterest);
+
+// Option only. Without a time range the latest hour is returned, a range spans at most 30 days.
+crate::define_request! {
+    Name => HistoricalVolatility;
+    Method => Method::GET;
+    Endpoint => "/v5/market/historical-volatility";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub base_coin: Option<String>,
+        pub quote_coin: Option<String>,
+        /// In days
+        pub period: Option<u64>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start_time: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end_time: Option<DateTime<Utc>>,
+    };
+    Response => Vec<HistoricalVolatility>;
+}
+
+// The long/short ratio of accounts. Linear and inverse only, use `Bybit::paginate`
+// to fetch every page.
+crate::define_request! {
+    Name => AccountRatio;
+    Method => Method::GET;
+    Endpoint => "/v5/market/account-ratio";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub period: IntervalTime,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start_time: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end_time: Option<DateTime<Utc>>,
+        pub limit: Option<u64>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<AccountRatio>;
+}
+
+crate::impl_cursor_request!(AccountRatioRequest => AccountRatio);
+
+crate::define_request! {
+    Name => Insurance;
+    Method => Method::GET;
+    Endpoint => "/v5/market/insurance";
+    Signed => false;
+    Request => {
+        pub coin: Option<String>,
+    };
+    Response => {
+        #[serde(with = "string_or_ts_milliseconds")]
+        pub updated_time: DateTime<Utc>,
+        pub list: Vec<Insurance>,
+    };
+}
+
+// Linear and inverse only. Without `symbol` every tier of every contract is returned
+// across pages, use `Bybit::paginate` to fetch them all.
+crate::define_request! {
+    Name => RiskLimit;
+    Method => Method::GET;
+    Endpoint => "/v5/market/risk-limit";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: Option<String>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<RiskLimit>;
+}
+
+crate::impl_cursor_request!(RiskLimitRequest => RiskLimit);
+
+// Dated futures and options only
+crate::define_request! {
+    Name => DeliveryPrice;
+    Method => Method::GET;
+    Endpoint => "/v5/market/delivery-price";
+    Signed => false;
+    Request => {
+        pub category: Product,
+        pub symbol: Option<String>,
+        pub base_coin: Option<String>,
+        pub settle_coin: Option<String>,
+        pub limit: Option<u64>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<DeliveryPrice>;
+}
+
+crate::impl_cursor_request!(DeliveryPriceRequest => DeliveryPrice);
+
+#[cfg(test)]
+mod test {
+    use super::{
+        FundingHistoryResponse, InsuranceResponse, KlineRequest, KlineResponse,
+        MarkPriceKlineResponse, OpenInterestRequest, RecentTradeResponse,
+    };
+    use crate::models::market::{
+        AccountRatio, DeliveryPrice, HistoricalVolatility, IntervalTime, OpenInterest, RiskLimit,
+    };
+    use crate::models::market::{
+        ContractType, Instrument, InstrumentStatus, KlineInterval, OptionsType, OrderBook, Tickers,
+    };
+    use crate::models::{Page, Product, Side};
+    use anyhow::Error;
+    use chrono::{TimeZone, Utc};
+    use fehler::throws;
+    use serde_json::from_str;
+
+    #[throws(Error)]
+    #[test]
+    fn instruments_info_spot() {
+        let page: Page<Instrument> = from_str(
+            r#"{"category":"spot","list":[{"symbol":"BTCUSDT","baseCoin":"BTC","quoteCoin":"USDT","innovation":"0","status":"Trading","marginTrading":"both","lotSizeFilter":{"basePrecision":"0.000001","quotePrecision":"0.00000001","minOrderQty":"0.000048","maxOrderQty":"71.73956243","minOrderAmt":"1","maxOrderAmt":"2000000"},"priceFilter":{"tickSize":"0.01"},"riskParameters":{"limitParameter":"0.05","marketParameter":"0.05"}}]}"#,
+        )?;
+        let btc = &page.list[0];
+        assert_eq!(btc.status, InstrumentStatus::Trading);
+        assert_eq!(btc.tick_size(), "0.01".parse()?);
+        assert_eq!(btc.qty_step(), Some("0.000001".parse()?));
+        assert!(btc.leverage_filter.is_none());
+        assert!(page.next_page_cursor.is_empty());
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn instruments_info_linear() {
+        let page: Page<Instrument> = from_str(
+            r#"{"category":"linear","list":[{"symbol":"BTCUSDT","contractType":"LinearPerpetual","status":"Trading","baseCoin":"BTC","quoteCoin":"USDT","launchTime":"1584230400000","deliveryTime":"0","deliveryFeeRate":"","priceScale":"2","leverageFilter":{"minLeverage":"1","maxLeverage":"100.00","leverageStep":"0.01"},"priceFilter":{"minPrice":"0.10","maxPrice":"199999.80","tickSize":"0.10"},"lotSizeFilter":{"maxOrderQty":"1190.000","minOrderQty":"0.001","qtyStep":"0.001","postOnlyMaxOrderQty":"1190.000","maxMktOrderQty":"500.000","minNotionalValue":"5"},"unifiedMarginTrade":true,"fundingInterval":480,"settleCoin":"USDT","copyTrading":"both","upperFundingRate":"0.00375","lowerFundingRate":"-0.00375"}],"nextPageCursor":"first%3DBTCUSDT%26last%3DBTCUSDT"}"#,
+        )?;
+        let btc = &page.list[0];
+        assert_eq!(btc.contract_type, Some(ContractType::LinearPerpetual));
+        assert_eq!(
+            btc.launch_time.map(|t| t.timestamp_millis()),
+            Some(1584230400000)
+        );
+        assert_eq!(btc.delivery_time, None);
+        assert_eq!(btc.delivery_fee_rate, None);
+        assert_eq!(btc.price_scale, Some(2));
+        assert_eq!(btc.qty_step(), Some("0.001".parse()?));
+        assert_eq!(
+            btc.leverage_filter.as_ref().map(|f| f.max_leverage),
+            Some("100".parse()?)
+        );
+        assert_eq!(btc.settle_coin.as_deref(), Some("USDT"));
+        assert_eq!(page.next_page_cursor, "first%3DBTCUSDT%26last%3DBTCUSDT");
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn instruments_info_option() {
+        let page: Page<Instrument> = from_str(
+            r#"{"category":"option","nextPageCursor":"","list":[{"symbol":"ETH-3JAN23-1250-P","status":"Trading","baseCoin":"ETH","quoteCoin":"USD","settleCoin":"USDC","optionsType":"Put","launchTime":"1672560000000","deliveryTime":"1672732800000","deliveryFeeRate":"0.00015","priceFilter":{"minPrice":"0.1","maxPrice":"10000000","tickSize":"0.1"},"lotSizeFilter":{"maxOrderQty":"1500","minOrderQty":"0.1","qtyStep":"0.1"}}]}"#,
+        )?;
+        let put = &page.list[0];
+        assert_eq!(put.options_type, Some(OptionsType::Put));
+        assert_eq!(
+            put.delivery_time.map(|t| t.timestamp_millis()),
+            Some(1672732800000)
+        );
+        assert_eq!(put.delivery_fee_rate, Some("0.00015".parse()?));
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn kline() {
+        let req = KlineRequest {
+            category: Product::CoinMFutures,
+            symbol: "BTCUSD".into(),
+            interval: KlineInterval::Hour1,
+            start: Some(Utc.timestamp_millis_opt(1670601600000).unwrap()),
+            end: Some(Utc.timestamp_millis_opt(1670608800000).unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_qs::to_string(&req)?,
+            "category=inverse&symbol=BTCUSD&interval=60&start=1670601600000&end=1670608800000"
+        );
+
+        let resp: KlineResponse = from_str(
+            r#"{"symbol":"BTCUSD","category":"inverse","list":[["1670608800000","17071","17073","17027","17055.5","268611","15.74462667"],["1670605200000","17071.5","17071.5","17061","17071","4177","0.24469757"]]}"#,
+        )?;
+        assert_eq!(resp.list.len(), 2);
+        assert_eq!(resp.list[0].start_time.timestamp_millis(), 1670608800000);
+        assert_eq!(resp.list[0].close, "17055.5".parse()?);
+        assert_eq!(resp.list[1].turnover, "0.24469757".parse()?);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn mark_price_kline() {
+        let resp: MarkPriceKlineResponse = from_str(
+            r#"{"symbol":"BTCUSDT","category":"linear","list":[["1670608800000","17164.16","17164.16","17121.5","17131.64"]]}"#,
+        )?;
+        assert_eq!(resp.list[0].low, "17121.5".parse()?);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn orderbook() {
+        let book: OrderBook = from_str(
+            r#"{"s":"BTCUSDT","a":[["65557.7","16.606555"]],"b":[["65485.47","47.081829"],["65485","0.1"]],"ts":1716863719031,"u":230704,"seq":1432604333,"cts":1716863718905}"#,
+        )?;
+        assert_eq!(book.bids.len(), 2);
+        assert_eq!(book.asks[0].price, "65557.7".parse()?);
+        assert_eq!(book.bids[1].size, "0.1".parse()?);
+        assert_eq!(book.ts.timestamp_millis(), 1716863719031);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn tickers() {
+        let tickers: Tickers = from_str(
+            r#"{"category":"inverse","list":[{"symbol":"BTCUSD","lastPrice":"16597.00","indexPrice":"16598.54","markPrice":"16596.00","prevPrice24h":"16464.50","price24hPcnt":"0.008047","highPrice24h":"30912.50","lowPrice24h":"15700.00","prevPrice1h":"16595.50","openInterest":"373504107","openInterestValue":"22505.67","turnover24h":"2352.94950046","volume24h":"49337318","fundingRate":"-0.001034","nextFundingTime":"1672387200000","predictedDeliveryPrice":"","basisRate":"","deliveryFeeRate":"","deliveryTime":"0","ask1Size":"1","bid1Price":"16596.00","ask1Price":"16597.50","bid1Size":"1","basis":""}]}"#,
+        )?;
+        match tickers {
+            Tickers::Inverse(list) => {
+                assert_eq!(list[0].funding_rate, Some("-0.001034".parse()?));
+                assert_eq!(list[0].delivery_time, None);
+                assert_eq!(list[0].basis, None);
+            }
+            t => panic!("unexpected tickers {t:?}"),
+        }
+
+        let tickers: Tickers = from_str(
+            r#"{"category":"option","list":[{"symbol":"BTC-30DEC22-18000-C","bid1Price":"0","bid1Size":"0","bid1Iv":"0","ask1Price":"435","ask1Size":"0.66","ask1Iv":"5","lastPrice":"435","highPrice24h":"435","lowPrice24h":"165","markPrice":"0.00000009","indexPrice":"16600.55","markIv":"0.7567","underlyingPrice":"16590.42","openInterest":"6.3","turnover24h":"2482.73","volume24h":"0.15","totalVolume":"99","totalTurnover":"1967653","delta":"0.00000001","gamma":"0.00000001","vega":"0.00000004","theta":"-0.00000152","predictedDeliveryPrice":"0","change24h":"86"}]}"#,
+        )?;
+        match tickers {
+            Tickers::Option(list) => assert_eq!(list[0].mark_iv, "0.7567".parse()?),
+            t => panic!("unexpected tickers {t:?}"),
+        }
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn recent_trade() {
+        let resp: RecentTradeResponse = from_str(
+            r#"{"category":"spot","list":[{"execId":"2100000000007764263","symbol":"BTCUSDT","price":"16618.49","size":"0.00012","side":"Buy","time":"1672052955758","isBlockTrade":false}]}"#,
+        )?;
+        assert_eq!(resp.list[0].side, Side::Buy);
+        assert_eq!(resp.list[0].mark_price, None);
+        assert_eq!(resp.list[0].time.timestamp_millis(), 1672052955758);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn funding_history() {
+        let resp: FundingHistoryResponse = from_str(
+            r#"{"category":"linear","list":[{"symbol":"ETHPERP","fundingRate":"0.0001","fundingRateTimestamp":"1672041600000"}]}"#,
+        )?;
+        assert_eq!(resp.category, Product::UsdMFutures);
+        assert_eq!(resp.list[0].funding_rate, "0.0001".parse()?);
+        assert_eq!(
+            resp.list[0].funding_rate_timestamp.timestamp_millis(),
+            1672041600000
+        );
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn open_interest() {
+        let query = serde_qs::to_string(&OpenInterestRequest {
+            category: Product::UsdMFutures,
+            symbol: "BTCUSDT".into(),
+            interval_time: IntervalTime::Hour1,
+            limit: Some(2),
+            ..Default::default()
+        })?;
+        assert_eq!(
+            query,
+            "category=linear&symbol=BTCUSDT&intervalTime=1h&limit=2"
+        );
+
+        let page: Page<OpenInterest> = from_str(
+            r#"{"symbol":"BTCUSD","category":"inverse","list":[{"openInterest":"461134384.00000000","timestamp":"1669571400000"},{"openInterest":"461134292.00000000","timestamp":"1669571100000"}],"nextPageCursor":"lastid%3D10"}"#,
+        )?;
+        assert_eq!(page.list[1].open_interest, "461134292".parse()?);
+        assert_eq!(page.list[0].timestamp.timestamp_millis(), 1669571400000);
+        assert_eq!(page.next_page_cursor, "lastid%3D10");
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn historical_volatility() {
+        let list: Vec<HistoricalVolatility> =
+            from_str(r#"[{"period":7,"value":"0.27545620","time":"1672232400000"}]"#)?;
+        assert_eq!(list[0].period, 7);
+        assert_eq!(list[0].value, "0.2754562".parse()?);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn account_ratio() {
+        let page: Page<AccountRatio> = from_str(
+            r#"{"list":[{"symbol":"BTCUSDT","buyRatio":"0.49","sellRatio":"0.51","timestamp":"1696906800000"}],"nextPageCursor":"lastid%3D0%26lasttime%3D1696906800"}"#,
+        )?;
+        assert_eq!(
+            page.list[0].buy_ratio + page.list[0].sell_ratio,
+            "1".parse()?
+        );
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn insurance() {
+        let resp: InsuranceResponse = from_str(
+            r#"{"updatedTime":"1714003200000","list":[{"coin":"USDT","symbols":"BTCUSDT,ETHUSDT","balance":"0.00952540","value":"0.00952540"}]}"#,
+        )?;
+        assert_eq!(resp.updated_time.timestamp_millis(), 1714003200000);
+        assert_eq!(resp.list[0].symbols.split(',').count(), 2);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn risk_limit() {
+        let page: Page<RiskLimit> = from_str(
+            r#"{"category":"inverse","list":[{"id":2,"symbol":"BTCUSD","riskLimitValue":"300","maintenanceMargin":"0.01","initialMargin":"0.02","isLowestRisk":0,"maxLeverage":"50.00","mmDeduction":"0.6"},{"id":1,"symbol":"BTCUSD","riskLimitValue":"150","maintenanceMargin":"0.005","initialMargin":"0.01","isLowestRisk":1,"maxLeverage":"100.00","mmDeduction":""}],"nextPageCursor":""}"#,
+        )?;
+        assert!(page.list[1].is_lowest_risk);
+        assert_eq!(page.list[1].mm_deduction, None);
+
+        let tier = |notional: &str| -> Option<u64> {
+            RiskLimit::find_tier(&page.list, "BTCUSD", notional.parse().unwrap()).map(|t| t.id)
+        };
+        assert_eq!(tier("0"), Some(1));
+        assert_eq!(tier("150"), Some(1));
+        assert_eq!(tier("150.01"), Some(2));
+        assert_eq!(tier("300.01"), None);
+        assert!(RiskLimit::find_tier(&page.list, "ETHUSD", "1".parse()?).is_none());
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn delivery_price() {
+        let page: Page<DeliveryPrice> = from_str(
+            r#"{"category":"option","nextPageCursor":"0%2C3","list":[{"symbol":"ETH-26DEC22-1400-C","deliveryPrice":"1220.728594450","deliveryTime":"1672041600000"}]}"#,
+        )?;
+        assert_eq!(page.list[0].delivery_price, "1220.72859445".parse()?);
+        assert_eq!(page.list[0].delivery_time.timestamp_millis(), 1672041600000);
+    }
 }
@@ -1,15 +1,18 @@
-mod account;
-mod market;
+pub mod account;
+pub mod market;
+pub mod position;
 pub mod trade;
 
 use crate::{
-    config::Config,
+    config::{Config, Environment},
     error::BybitError::{self, *},
     models::Product,
+    websocket::{BybitWebsocket, ParseMessage},
     BybitResponseError,
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use fehler::{throw, throws};
+use futures::{stream, Stream, TryStreamExt};
 use hex::encode as hexify;
 use hmac::{Hmac, Mac};
 use log::{debug, trace};
@@ -17,27 +20,69 @@ use log::{debug, trace};
 use owning_ref::OwningHandle;
 use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT},
-    Client, Method, Response,
+    Client, Method, StatusCode,
 };
-use serde::{de::DeserializeOwned, Serialize};
-use serde_json::from_str;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{from_str, value::RawValue, Value};
 use sha2::Sha256;
 #[cfg(feature = "zero-copy")]
 use std::ops::Deref;
+use std::ops::Range;
+use std::time::Duration;
 
+/// A REST request. Endpoints taking a V5 `category` carry it as a `Product` field.
 pub trait Request: Serialize {
-    const PRODUCT: Product;
     const ENDPOINT: &'static str;
     const METHOD: Method;
     const KEYED: bool = false; // SIGNED imples KEYED no matter KEYED is true or false
     const SIGNED: bool = false;
+    const BODY: BodyEncoding = BodyEncoding::Json; // Ignored by GET requests, which only carry a query string
     type Response: DeserializeOwned;
+
+    /// Rejects parameter combinations Bybit would refuse, before anything is sent.
+    fn validate(&self) -> Result<(), BybitError> {
+        Ok(())
+    }
+}
+
+/// A request to an endpoint paged by `cursor`, whose response is a `Page`.
+/// Implemented with `impl_cursor_request!`.
+pub trait CursorRequest: Request + Clone {
+    type Item;
+    fn set_cursor(&mut self, cursor: String);
+    fn split_page(resp: Self::Response) -> (Vec<Self::Item>, String);
+}
+
+/// How the parameters of a non-GET request are encoded into the request body.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BodyEncoding {
+    /// `application/json`, used by all the V5 write endpoints.
+    Json,
+    /// `application/x-www-form-urlencoded`.
+    Form,
+}
+
+impl BodyEncoding {
+    #[throws(BybitError)]
+    fn encode<R: Serialize>(self, req: &R) -> String {
+        match self {
+            BodyEncoding::Json => serde_json::to_string(req)?,
+            BodyEncoding::Form => serde_qs::to_string(req)?,
+        }
+    }
+
+    fn content_type(self) -> HeaderValue {
+        match self {
+            BodyEncoding::Json => HeaderValue::from_static("application/json"),
+            BodyEncoding::Form => HeaderValue::from_static("application/x-www-form-urlencoded"),
+        }
+    }
 }
 
+/// The REST client. Credentials, endpoints and `recv_window` all come from its `Config`,
+/// which is also used to open websockets through `Bybit::websocket`.
 #[derive(Clone, Default)]
 pub struct Bybit {
-    key: Option<String>,
-    secret: Option<String>,
     client: Client,
     config: Config,
 }
@@ -47,26 +92,36 @@ impl Bybit {
         Default::default()
     }
 
-    pub fn with_key(api_key: &str) -> Self {
+    pub fn builder() -> BybitBuilder {
+        BybitBuilder::default()
+    }
+
+    pub fn from_config(config: Config) -> Self {
         Bybit {
             client: Client::new(),
-            key: Some(api_key.into()),
-            secret: None,
-            config: Config::default(),
+            config,
         }
     }
 
+    pub fn with_key(api_key: &str) -> Self {
+        Self::from_config(Config {
+            api_key: Some(api_key.into()),
+            ..Config::default()
+        })
+    }
+
     pub fn with_key_and_secret(api_key: &str, api_secret: &str) -> Self {
-        Bybit {
-            client: Client::new(),
-            key: Some(api_key.into()),
-            secret: Some(api_secret.into()),
-            config: Config::default(),
-        }
+        Self::from_config(Config::private_new(api_key.into(), api_secret.into()))
     }
 
-    pub fn config(&mut self, config: Config) {
-        self.config = config;
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Connects a websocket sharing the endpoints and credentials of this client.
+    #[throws(BybitError)]
+    pub async fn websocket<M: ParseMessage>(&self) -> BybitWebsocket<M> {
+        BybitWebsocket::new(self.config.clone()).await?
     }
 
     #[throws(BybitError)]
@@ -74,52 +129,122 @@ impl Bybit {
     where
         R: Request,
     {
-        let mut params = if matches!(R::METHOD, Method::GET) {
+        let body = self.send(req).await?;
+        self.handle_response(body)?
+    }
+
+    /// Same as `request` but always returns an owned response, for the helpers built on top of it.
+    #[throws(BybitError)]
+    pub(crate) async fn request_owned<R>(&self, req: R) -> R::Response
+    where
+        R: Request,
+    {
+        parse_result(&self.send(req).await?)?
+    }
+
+    /// Same as `request_owned` but also returns `retExtInfo`, where batch endpoints report
+    /// the outcome of every item.
+    #[throws(BybitError)]
+    pub(crate) async fn request_with_ext_info<R>(&self, req: R) -> (R::Response, Value)
+    where
+        R: Request,
+    {
+        parse_result_with_ext_info(&self.send(req).await?)?
+    }
+
+    /// Streams every item of a cursor paged endpoint, following `nextPageCursor` until the last page.
+    ///
+    /// e.g. `bybit.paginate(InstrumentsInfoRequest { category, ..Default::default() }).try_collect::<Vec<_>>()`
+    pub fn paginate<R>(&self, req: R) -> impl Stream<Item = Result<R::Item, BybitError>> + '_
+    where
+        R: CursorRequest + 'static,
+    {
+        stream::try_unfold(Some(req), move |req| async move {
+            let mut req = match req {
+                Some(req) => req,
+                None => return Ok::<_, BybitError>(None),
+            };
+            let (items, cursor) = R::split_page(self.request_owned(req.clone()).await?);
+            let next = if cursor.is_empty() || items.is_empty() {
+                None
+            } else {
+                req.set_cursor(cursor);
+                Some(req)
+            };
+            Ok(Some((
+                stream::iter(items.into_iter().map(Ok::<_, BybitError>)),
+                next,
+            )))
+        })
+        .try_flatten()
+    }
+
+    #[throws(BybitError)]
+    async fn send<R>(&self, req: R) -> String
+    where
+        R: Request,
+    {
+        req.validate()?;
+
+        let params = if matches!(R::METHOD, Method::GET) {
             serde_qs::to_string(&req)?
         } else {
             String::new()
         };
 
         let body = if !matches!(R::METHOD, Method::GET) {
-            serde_qs::to_string(&req)?
+            R::BODY.encode(&req)?
         } else {
             String::new()
         };
 
-        if R::SIGNED {
-            if !params.is_empty() {
-                params.push('&');
-            }
-            params.push_str(&format!("timestamp={}", Utc::now().timestamp_millis()));
-            params.push_str(&format!("&recvWindow={}", self.config.recv_window));
-
-            let signature = self.signature(&params, &body)?;
-            params.push_str(&format!("&signature={}", signature));
-        }
-
         let path = R::ENDPOINT.to_string();
 
         let base = &self.config.rest_api_endpoint;
-        let url = format!("{base}{path}?{params}");
+        let url = if params.is_empty() {
+            format!("{base}{path}")
+        } else {
+            format!("{base}{path}?{params}")
+        };
 
         let mut custom_headers = HeaderMap::new();
         custom_headers.insert(USER_AGENT, HeaderValue::from_static("bybit-async-rs"));
         if !body.is_empty() {
-            custom_headers.insert(
-                CONTENT_TYPE,
-                HeaderValue::from_static("application/x-www-form-urlencoded"),
-            );
+            custom_headers.insert(CONTENT_TYPE, R::BODY.content_type());
         }
         if R::SIGNED || R::KEYED {
-            let key = match &self.key {
+            let key = match &self.config.api_key {
                 Some(key) => key,
                 None => throw!(MissingApiKey),
             };
             custom_headers.insert(
-                HeaderName::from_static("x-mbx-apikey"),
+                HeaderName::from_static("x-bapi-api-key"),
                 HeaderValue::from_str(key)?,
             );
         }
+        if R::SIGNED {
+            // GET requests sign the query string, the others sign the exact body bytes sent below
+            let payload = if matches!(R::METHOD, Method::GET) {
+                &params
+            } else {
+                &body
+            };
+            let timestamp = Utc::now().timestamp_millis();
+            let signature = self.signature(timestamp, payload)?;
+
+            custom_headers.insert(
+                HeaderName::from_static("x-bapi-timestamp"),
+                HeaderValue::from(timestamp),
+            );
+            custom_headers.insert(
+                HeaderName::from_static("x-bapi-recv-window"),
+                HeaderValue::from(self.config.recv_window),
+            );
+            custom_headers.insert(
+                HeaderName::from_static("x-bapi-sign"),
+                HeaderValue::from_str(&signature)?,
+            );
+        }
 
         debug!("[REST] url: {url}, body: {body}");
 
@@ -131,64 +256,198 @@ impl Bybit {
             .send()
             .await?;
 
-        self.handle_response(resp).await?
+        let status = resp.status();
+        let body = resp.text().await?;
+
+        if cfg!(feature = "print-response") {
+            debug!("Response is {status} {body}");
+        };
+
+        check_status(status, body)?
     }
 
     #[throws(BybitError)]
-    fn signature(&self, params: &str, body: &str) -> String {
-        let secret = match &self.secret {
+    fn signature(&self, timestamp: i64, payload: &str) -> String {
+        let key = match &self.config.api_key {
+            Some(k) => k,
+            None => throw!(MissingApiKey),
+        };
+        let secret = match &self.config.api_secret {
             Some(s) => s,
             None => throw!(MissingApiSecret),
         };
-        // Signature: hex(HMAC_SHA256(queries + data))
+        // Signature: hex(HMAC_SHA256(timestamp + api_key + recv_window + payload))
         let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
-        let sign_message = format!("{}{}", params, body);
+        let sign_message = format!("{}{}{}{}", timestamp, key, self.config.recv_window, payload);
         trace!("Sign message: {}", sign_message);
         mac.update(sign_message.as_bytes());
-        let signature = hexify(mac.finalize().into_bytes());
-        signature
+        hexify(mac.finalize().into_bytes())
     }
 
     #[cfg(not(feature = "zero-copy"))]
     #[throws(BybitError)]
-    async fn handle_response<O: DeserializeOwned>(&self, resp: Response) -> RestResponse<O> {
-        let status = resp.status();
-        let body = resp.text().await?;
+    fn handle_response<O: DeserializeOwned>(&self, body: String) -> RestResponse<O> {
+        parse_result(&body)?
+    }
 
-        if cfg!(feature = "print-response") {
-            debug!("Response is {status} {body}");
+    #[cfg(feature = "zero-copy")]
+    #[throws(BybitError)]
+    fn handle_response<O: DeserializeOwned>(&self, body: String) -> RestResponse<O> {
+        OwningHandle::try_new(body, |body| -> Result<_, BybitError> {
+            let body = unsafe { &*body };
+            Ok(C(parse_result(body)?))
+        })?
+    }
+}
+
+/// Builds a `Bybit` client, e.g.
+/// `Bybit::builder().key_and_secret(key, secret).environment(Environment::Testnet).build()`.
+#[derive(Default)]
+pub struct BybitBuilder {
+    config: Config,
+    client: Option<Client>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+}
+
+impl BybitBuilder {
+    /// Replaces the whole config, including credentials.
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn key_and_secret(mut self, api_key: &str, api_secret: &str) -> Self {
+        self.config.api_key = Some(api_key.into());
+        self.config.api_secret = Some(api_secret.into());
+        self
+    }
+
+    pub fn key(mut self, api_key: &str) -> Self {
+        self.config.api_key = Some(api_key.into());
+        self
+    }
+
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.config = self.config.set_environment(environment);
+        self
+    }
+
+    pub fn rest_api_endpoint(mut self, endpoint: &str) -> Self {
+        self.config.rest_api_endpoint = endpoint.into();
+        self
+    }
+
+    pub fn product(mut self, product: Product) -> Self {
+        self.config.product = product;
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.config.recv_window = recv_window;
+        self
+    }
+
+    /// Ignored if a custom client is given.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Ignored if a custom client is given.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    #[throws(BybitError)]
+    pub fn build(self) -> Bybit {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                builder.build()?
+            }
         };
 
-        match from_str(&body) {
-            Ok(v) => v,
-            Err(e) => match from_str::<BybitResponseError>(&body) {
-                Ok(e) => throw!(e),
-                Err(_) => throw!(e),
-            },
+        Bybit {
+            client,
+            config: self.config,
         }
     }
+}
 
-    #[cfg(feature = "zero-copy")]
-    #[throws(BybitError)]
-    async fn handle_response<O: DeserializeOwned>(&self, resp: Response) -> RestResponse<O> {
-        let status = resp.status();
-        let body = resp.text().await?;
+/// Splits `range` into consecutive windows of at most `step`, as inclusive `(start, end)` pairs
+/// for endpoints limiting the span of `startTime` and `endTime`.
+pub(crate) fn split_range(
+    range: Range<DateTime<Utc>>,
+    step: chrono::Duration,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut windows = vec![];
+    let mut start = range.start;
+    while start < range.end {
+        let end = (start + step).min(range.end);
+        windows.push((start, end - chrono::Duration::milliseconds(1)));
+        start = end;
+    }
+    windows
+}
 
-        if cfg!(feature = "print-response") {
-            debug!("Response is {status} {body}");
-        };
+// Every V5 response is wrapped as {retCode, retMsg, result, retExtInfo, time}
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Envelope<'a> {
+    ret_code: i64,
+    ret_msg: String,
+    #[serde(borrow)]
+    result: Option<&'a RawValue>,
+    #[serde(default)]
+    ret_ext_info: Value,
+    #[serde(default)]
+    time: u64,
+}
 
-        OwningHandle::try_new(body, |body| -> Result<_, BybitError> {
-            let body = unsafe { &*body };
-            match from_str(body) {
-                Ok(v) => Ok(C(v)),
-                Err(e) => match from_str::<BybitResponseError>(&body) {
-                    Ok(e) => throw!(e),
-                    Err(_) => throw!(e),
-                },
-            }
-        })?
+/// Keeps non-2xx responses carrying an envelope, whose `retCode` is more precise than the
+/// status, and turns the others (IP rate limit, gateway errors) into `BybitError::HttpStatus`.
+#[throws(BybitError)]
+fn check_status(status: StatusCode, body: String) -> String {
+    if !status.is_success() && from_str::<Envelope>(&body).is_err() {
+        throw!(HttpStatus(status, body))
     }
+    body
+}
+
+/// Unwraps the V5 response envelope, turning a non-zero `retCode` into `BybitError::BybitResponse`.
+#[throws(BybitError)]
+fn parse_result<'a, O: Deserialize<'a>>(body: &'a str) -> O {
+    parse_result_with_ext_info(body)?.0
+}
+
+#[throws(BybitError)]
+fn parse_result_with_ext_info<'a, O: Deserialize<'a>>(body: &'a str) -> (O, Value) {
+    let envelope: Envelope<'a> = from_str(body)?;
+    if envelope.ret_code != 0 {
+        throw!(BybitResponseError {
+            ret_code: envelope.ret_code,
+            ret_msg: envelope.ret_msg,
+            ret_ext_info: envelope.ret_ext_info,
+            time: envelope.time,
+        })
+    }
+
+    let result = from_str(envelope.result.map_or("null", RawValue::get))?;
+    (result, envelope.ret_ext_info)
 }
 
 #[cfg(feature = "zero-copy")]
@@ -210,139 +469,193 @@ pub type RestResponse<O> = O;
 
 #[cfg(test)]
 mod test {
-    use super::Bybit;
+    use super::{check_status, parse_result, BodyEncoding, Bybit, Request};
+    use crate::{config::Config, models::Product, BybitError};
     use anyhow::Error;
+    use chrono::{DateTime, Duration, Utc};
     use fehler::throws;
-    use url::{form_urlencoded::Serializer, Url};
+    use reqwest::{Method, StatusCode};
+    use rust_decimal::Decimal;
+    use url::Url;
+
+    crate::define_request! {
+        Name => Dummy;
+        Method => Method::POST;
+        Endpoint => "/v5/dummy";
+        Signed => true;
+        Request => {
+            pub category: Product,
+            pub symbol: String,
+            pub qty: Decimal,
+            pub price: Option<Decimal>,
+            pub order_link_id: Option<String>,
+        };
+        Response => {};
+    }
 
     #[throws(Error)]
     #[test]
-    fn signature_query() {
-        let tr = Bybit::with_key_and_secret(
-            "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A",
-            "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j",
+    fn json_body() {
+        let req = DummyRequest {
+            category: Product::UsdMFutures,
+            symbol: "BTCUSDT".into(),
+            qty: "0.01".parse()?,
+            order_link_id: Some("bybit-async-rs".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            DummyRequest::BODY.encode(&req)?,
+            r#"{"category":"linear","symbol":"BTCUSDT","qty":"0.01","orderLinkId":"bybit-async-rs"}"#
+        );
+        assert_eq!(
+            BodyEncoding::Form.encode(&req)?,
+            "category=linear&symbol=BTCUSDT&qty=0.01&orderLinkId=bybit-async-rs"
         );
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn envelope_result() {
+        let _: DummyResponse = parse_result(
+            r#"{"retCode":0,"retMsg":"OK","result":{},"retExtInfo":{},"time":1672211918471}"#,
+        )?;
+        let resp: Vec<u64> = parse_result(
+            r#"{"retCode":0,"retMsg":"OK","result":[1,2],"retExtInfo":{},"time":1672211918471}"#,
+        )?;
+        assert_eq!(resp, vec![1, 2]);
+    }
+
+    #[test]
+    fn envelope_error() {
+        let err = parse_result::<DummyResponse>(
+            r#"{"retCode":10001,"retMsg":"params error: symbol invalid","result":{},"retExtInfo":{"list":[]},"time":1672211918471}"#,
+        )
+        .unwrap_err();
+        match err {
+            BybitError::BybitResponse {
+                code,
+                msg,
+                ext_info,
+                time,
+            } => {
+                assert_eq!(code, 10001);
+                assert_eq!(msg, "params error: symbol invalid");
+                assert_eq!(ext_info, serde_json::json!({"list": []}));
+                assert_eq!(time, 1672211918471);
+            }
+            e => panic!("unexpected error {e:?}"),
+        }
+    }
+
+    #[test]
+    fn http_status() {
+        let err = check_status(StatusCode::FORBIDDEN, "access too frequent".into()).unwrap_err();
+        assert!(matches!(
+            err,
+            BybitError::HttpStatus(StatusCode::FORBIDDEN, _)
+        ));
+        assert!(err.is_rate_limited());
+
+        let err = check_status(StatusCode::BAD_GATEWAY, "<html>502</html>".into()).unwrap_err();
+        assert!(err.is_retryable());
+
+        // An envelope is still parsed for its retCode
+        let body =
+            r#"{"retCode":10006,"retMsg":"Too many visits!","result":{},"retExtInfo":{},"time":0}"#;
+        let err = parse_result::<DummyResponse>(
+            &check_status(StatusCode::TOO_MANY_REQUESTS, body.into()).unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(err.code(), Some(crate::BybitErrorCode::TooManyVisits));
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn signature_query() {
+        let tr = Bybit::with_key_and_secret("XXXXXXXXXX", "YYYYYYYYYY");
         let sig = tr.signature(
-            &Url::parse_with_params(
-                "http://a.com/api/v1/test",
-                &[
-                    ("symbol", "LTCBTC"),
-                    ("side", "BUY"),
-                    ("type", "LIMIT"),
-                    ("timeInForce", "GTC"),
-                    ("quantity", "1"),
-                    ("price", "0.1"),
-                    ("recvWindow", "5000"),
-                    ("timestamp", "1499827319559"),
-                ],
+            1658385579423,
+            Url::parse_with_params(
+                "http://a.com/v5/order/realtime",
+                &[("category", "option"), ("symbol", "BTC-29JUL22-25000-C")],
             )?
             .query()
             .unwrap_or_default(),
-            "",
         )?;
         assert_eq!(
             sig,
-            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+            "c2bc57ed60d70ba97f91c7cced8aa51da98f136d2793873d0fd064295c380c38"
         );
     }
 
     #[throws(Error)]
     #[test]
     fn signature_body() {
-        let tr = Bybit::with_key_and_secret(
-            "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A",
-            "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j",
-        );
-        let mut s = Serializer::new(String::new());
-        s.extend_pairs(&[
-            ("symbol", "LTCBTC"),
-            ("side", "BUY"),
-            ("type", "LIMIT"),
-            ("timeInForce", "GTC"),
-            ("quantity", "1"),
-            ("price", "0.1"),
-            ("recvWindow", "5000"),
-            ("timestamp", "1499827319559"),
-        ]);
-
+        let tr = Bybit::with_key_and_secret("XXXXXXXXXX", "YYYYYYYYYY");
         let sig = tr.signature(
-            &Url::parse("http://a.com/api/v1/test")?
-                .query()
-                .unwrap_or_default(),
-            &s.finish(),
+            1658384314791,
+            r#"{"category":"option","symbol":"BTC-29JUL22-25000-C","orderType":"Limit","side":"Buy","qty":"1","price":"0.1","orderLinkId":"bybit-async-rs"}"#,
         )?;
         assert_eq!(
             sig,
-            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+            "04856ad49439db6f19f0f2d3a8d311096f05adabc0bc9d2cc80347e4a46f7388"
         );
     }
 
     #[throws(Error)]
     #[test]
-    fn signature_query_body() {
-        let tr = Bybit::with_key_and_secret(
-            "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A",
-            "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j",
+    fn signature_empty_payload() {
+        let tr = Bybit::with_key_and_secret("XXXXXXXXXX", "YYYYYYYYYY");
+        let sig = tr.signature(1658384314791, "")?;
+        assert_eq!(
+            sig,
+            "e5b212f0e7e7a8bcdfe57d2dbf1618123747c43c601efa628c9ac63c2dd4f35e"
         );
+    }
 
-        let mut s = Serializer::new(String::new());
-        s.extend_pairs(&[
-            ("quantity", "1"),
-            ("price", "0.1"),
-            ("recvWindow", "5000"),
-            ("timestamp", "1499827319559"),
-        ]);
+    // REST used to ignore the credentials of the `Config` it was built from
+    #[throws(Error)]
+    #[test]
+    fn signature_from_config() {
+        let config = Config::private_new("XXXXXXXXXX".into(), "YYYYYYYYYY".into());
+        for tr in [
+            Bybit::from_config(config.clone()),
+            Bybit::builder().config(config).build()?,
+        ] {
+            let sig = tr.signature(1658384314791, "")?;
+            assert_eq!(
+                sig,
+                "e5b212f0e7e7a8bcdfe57d2dbf1618123747c43c601efa628c9ac63c2dd4f35e"
+            );
+        }
+    }
 
-        let sig = tr.signature(
-            &Url::parse_with_params(
-                "http://a.com/api/v1/order",
-                &[
-                    ("symbol", "LTCBTC"),
-                    ("side", "BUY"),
-                    ("type", "LIMIT"),
-                    ("timeInForce", "GTC"),
-                ],
-            )?
-            .query()
-            .unwrap_or_default(),
-            &s.finish(),
-        )?;
+    #[throws(Error)]
+    #[test]
+    fn signature_recv_window() {
+        let tr = Bybit::builder()
+            .key_and_secret("XXXXXXXXXX", "YYYYYYYYYY")
+            .recv_window(20000)
+            .build()?;
+        let sig = tr.signature(1658384314791, "category=linear&symbol=BTCUSDT")?;
         assert_eq!(
             sig,
-            "0fd168b8ddb4876a0358a8d14d0c9f3da0e9b20c5d52b2a00fcf7d1c602f9a77"
+            "c294d97ec67abc82d84c2809a9217f424242704d88dc8f4ce1895f7da30a7e6d"
         );
     }
 
     #[throws(Error)]
     #[test]
-    fn signature_body2() {
-        let tr = Bybit::with_key_and_secret(
-            "vj1e6h50pFN9CsXT5nsL25JkTuBHkKw3zJhsA6OPtruIRalm20vTuXqF3htCZeWW",
-            "5Cjj09rLKWNVe7fSalqgpilh5I3y6pPplhOukZChkusLqqi9mQyFk34kJJBTdlEJ",
-        );
-
-        let q = &mut [
-            ("symbol", "ETHBTC"),
-            ("side", "BUY"),
-            ("type", "LIMIT"),
-            ("timeInForce", "GTC"),
-            ("quantity", "1"),
-            ("price", "0.1"),
-            ("recvWindow", "5000"),
-            ("timestamp", "1540687064555"),
-        ];
-        q.sort();
-        let q: Vec<_> = q.into_iter().map(|(k, v)| format!("{}={}", k, v)).collect();
-        let q = q.join("&");
-        let sig = tr.signature(
-            &Url::parse("http://a.com/api/v1/test")?
-                .query()
-                .unwrap_or_default(),
-            &q,
-        )?;
+    fn split_range_windows() {
+        let start: DateTime<Utc> = "2024-01-01T00:00:00Z".parse()?;
+        let windows = super::split_range(start..start + Duration::days(15), Duration::days(7));
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[0].0, start);
+        assert_eq!(windows[1].0 - windows[0].1, Duration::milliseconds(1));
         assert_eq!(
-            sig,
-            "1ee5a75760b9496a2144a22116e02bc0b7fdcf828781fa87ca273540dfcf2cb0"
+            windows[2].1,
+            start + Duration::days(15) - Duration::milliseconds(1)
         );
+        assert!(super::split_range(start..start, Duration::days(7)).is_empty());
     }
 }
@@ -0,0 +1,409 @@
+use crate::models::position::{
+    ClosedPnl, MovePosition, MovePositionRecord, MovePositionStatus, Position,
+};
+use crate::models::{
+    OrderType, Page, PositionIdx, PositionMode, Product, TpSlMode, TradeMode, TriggerBy,
+};
+use crate::rest::split_range;
+use crate::rest::trade::check_tpsl_limit_price;
+use crate::{Bybit, BybitError};
+use chrono::serde::ts_milliseconds_option;
+use chrono::{DateTime, Duration, Utc};
+use fehler::throws;
+use futures::{stream, Stream, StreamExt};
+use reqwest::Method;
+use rust_decimal::Decimal;
+use serde_with::{As, BoolFromInt};
+use std::ops::Range;
+
+// Linear and inverse need `symbol` or `settle_coin`, inverse also accepts neither.
+// Use `Bybit::paginate` to stream every position.
+crate::define_request! {
+    Name => PositionList;
+    Method => Method::GET;
+    Endpoint => "/v5/position/list";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: Option<String>,
+        pub base_coin: Option<String>,
+        pub settle_coin: Option<String>,
+        pub limit: Option<u64>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<Position>;
+}
+
+crate::impl_cursor_request!(PositionListRequest => Position);
+
+// One-way mode and unified accounts take the same leverage for both sides.
+crate::define_request! {
+    Name => SetLeverage;
+    Method => Method::POST;
+    Endpoint => "/v5/position/set-leverage";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub buy_leverage: Decimal,
+        pub sell_leverage: Decimal,
+    };
+    Response => {};
+}
+
+// Switches the mode of `symbol`, or of every symbol settled in `coin`.
+crate::define_request! {
+    Name => SwitchMode;
+    Method => Method::POST;
+    Endpoint => "/v5/position/switch-mode";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: Option<String>,
+        pub coin: Option<String>,
+        pub mode: PositionMode,
+    };
+    Response => {};
+}
+
+// Classic accounts only, unified accounts set the margin mode on the whole account.
+crate::define_request! {
+    Name => SwitchIsolated;
+    Method => Method::POST;
+    Endpoint => "/v5/position/switch-isolated";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub trade_mode: TradeMode,
+        pub buy_leverage: Decimal,
+        pub sell_leverage: Decimal,
+    };
+    Response => {};
+}
+
+crate::define_request! {
+    Name => SetTpSlMode;
+    Method => Method::POST;
+    Endpoint => "/v5/position/set-tpsl-mode";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub tp_sl_mode: TpSlMode,
+    };
+    Response => {
+        pub tp_sl_mode: TpSlMode,
+    };
+}
+
+// Sets the TP/SL and trailing stop of a position. `Full` applies to the whole position,
+// `Partial` to `tp_size`/`sl_size` and also takes limit TP/SL orders. `0` cancels a price.
+crate::define_request! {
+    Name => TradingStop;
+    Method => Method::POST;
+    Endpoint => "/v5/position/trading-stop";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub tpsl_mode: TpSlMode,
+        pub position_idx: PositionIdx,
+        pub take_profit: Option<Decimal>,
+        pub stop_loss: Option<Decimal>,
+        /// The distance to the price, not a price
+        pub trailing_stop: Option<Decimal>,
+        pub tp_trigger_by: Option<TriggerBy>,
+        pub sl_trigger_by: Option<TriggerBy>,
+        /// The price activating the trailing stop
+        pub active_price: Option<Decimal>,
+        pub tp_size: Option<Decimal>,
+        pub sl_size: Option<Decimal>,
+        pub tp_limit_price: Option<Decimal>,
+        pub sl_limit_price: Option<Decimal>,
+        pub tp_order_type: Option<OrderType>,
+        pub sl_order_type: Option<OrderType>,
+    };
+    Response => {};
+    Validate => validate_trading_stop;
+}
+
+#[throws(BybitError)]
+fn validate_trading_stop(req: &TradingStopRequest) {
+    let (category, mode) = (req.category, Some(req.tpsl_mode));
+    check_tpsl_limit_price("tp", category, mode, req.tp_order_type, req.tp_limit_price)?;
+    check_tpsl_limit_price("sl", category, mode, req.sl_order_type, req.sl_limit_price)?;
+}
+
+// Isolated margin only, a negative `margin` reduces it.
+crate::define_request! {
+    Name => AddMargin;
+    Method => Method::POST;
+    Endpoint => "/v5/position/add-margin";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        pub margin: Decimal,
+        /// Required in hedge mode
+        pub position_idx: Option<PositionIdx>,
+    };
+    Response => Position;
+}
+
+// Isolated margin only
+crate::define_request! {
+    Name => SetAutoAddMargin;
+    Method => Method::POST;
+    Endpoint => "/v5/position/set-auto-add-margin";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        #[serde(with = "As::<BoolFromInt>")]
+        pub auto_add_margin: bool,
+        /// Required in hedge mode
+        pub position_idx: Option<PositionIdx>,
+    };
+    Response => {};
+}
+
+// Newest first. A time range spans at most 7 days and defaults to the last 7 days, and only
+// the last 2 years are kept. Use `Bybit::closed_pnl` for longer ranges.
+crate::define_request! {
+    Name => ClosedPnl;
+    Method => Method::GET;
+    Endpoint => "/v5/position/closed-pnl";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: Option<String>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start_time: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end_time: Option<DateTime<Utc>>,
+        pub limit: Option<u64>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<ClosedPnl>;
+}
+
+crate::impl_cursor_request!(ClosedPnlRequest => ClosedPnl);
+
+pub const CLOSED_PNL_WINDOW: Duration = Duration::days(7);
+
+impl Bybit {
+    /// Streams the closed PnL of `range`, newest first, walking back through consecutive
+    /// windows of `CLOSED_PNL_WINDOW` and every page of each.
+    pub fn closed_pnl(
+        &self,
+        category: Product,
+        symbol: Option<&str>,
+        range: Range<DateTime<Utc>>,
+    ) -> impl Stream<Item = Result<ClosedPnl, BybitError>> + '_ {
+        let symbol = symbol.map(str::to_string);
+        stream::iter(split_range(range, CLOSED_PNL_WINDOW).into_iter().rev())
+            .map(move |(start, end)| {
+                self.paginate(ClosedPnlRequest {
+                    category,
+                    symbol: symbol.clone(),
+                    start_time: Some(start),
+                    end_time: Some(end),
+                    limit: Some(100),
+                    cursor: None,
+                })
+            })
+            .flatten()
+    }
+}
+
+// Moves positions between two UIDs of the same master account as a block trade.
+// Unified accounts only, the UIDs need the permission to move positions.
+crate::define_request! {
+    Name => MovePositions;
+    Method => Method::POST;
+    Endpoint => "/v5/position/move-positions";
+    Signed => true;
+    Request => {
+        pub from_uid: String,
+        pub to_uid: String,
+        /// Up to 25 positions
+        pub list: Vec<MovePosition>,
+    };
+    Response => {
+        pub block_trade_id: String,
+        pub status: MovePositionStatus,
+        #[serde(default)]
+        pub reject_party: String,
+    };
+}
+
+// Use `Bybit::paginate` to stream every record.
+crate::define_request! {
+    Name => MovePositionHistory;
+    Method => Method::GET;
+    Endpoint => "/v5/position/move-history";
+    Signed => true;
+    Request => {
+        pub category: Option<Product>,
+        pub symbol: Option<String>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start_time: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end_time: Option<DateTime<Utc>>,
+        pub status: Option<MovePositionStatus>,
+        pub block_trade_id: Option<String>,
+        pub limit: Option<u64>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<MovePositionRecord>;
+}
+
+crate::impl_cursor_request!(MovePositionHistoryRequest => MovePositionRecord);
+
+#[cfg(test)]
+mod test {
+    use super::{
+        MovePositionsRequest, MovePositionsResponse, SetAutoAddMarginRequest, SetTpSlModeRequest,
+        SwitchModeRequest, TradingStopRequest,
+    };
+    use crate::models::position::{
+        ClosedPnl, MovePosition, MovePositionRecord, MovePositionStatus, Position, PositionStatus,
+    };
+    use crate::models::ExecutionType;
+    use crate::models::OrderType;
+    use crate::models::{Page, PositionIdx, PositionMode, Product, Side, TpSlMode, TradeMode};
+    use crate::rest::Request;
+    use anyhow::Error;
+    use fehler::throws;
+    use serde_json::from_str;
+
+    #[throws(Error)]
+    #[test]
+    fn position_list() {
+        let page: Page<Position> = from_str(
+            r#"{"list":[{"positionIdx":0,"riskId":1,"riskLimitValue":"150","symbol":"BTCUSD","side":"Sell","size":"300","avgPrice":"27464.50441675","positionValue":"0.01092319","tradeMode":0,"positionStatus":"Normal","autoAddMargin":1,"adlRankIndicator":2,"leverage":"10","positionBalance":"0.00139186","markPrice":"28224.50","liqPrice":"","bustPrice":"999999.00","positionMM":"0.0000015","positionIM":"0.00010923","tpslMode":"Full","takeProfit":"0.00","stopLoss":"0.00","trailingStop":"0.00","unrealisedPnl":"-0.00029413","curRealisedPnl":"0.00013123","cumRealisedPnl":"-0.00096902","seq":5723621632,"isReduceOnly":false,"mmrSysUpdatedTime":"","leverageSysUpdatedTime":"","sessionAvgPrice":"","createdTime":"1676538056258","updatedTime":"1697673600012"},{"positionIdx":0,"symbol":"ETHUSD","side":"","size":"0","avgPrice":"0","positionValue":"0","positionStatus":"Normal","autoAddMargin":0,"adlRankIndicator":0,"leverage":"","markPrice":"1500","liqPrice":"","bustPrice":"","positionMM":"","positionIM":"","unrealisedPnl":"0","cumRealisedPnl":"0","createdTime":"0","updatedTime":"1697673600012"}],"category":"inverse","nextPageCursor":"updateAt%3D1697673600012%26symbol%3DBTCUSD"}"#,
+        )?;
+        let btc = &page.list[0];
+        assert_eq!(btc.position_idx, PositionIdx::OneWay);
+        assert_eq!(btc.side, Some(Side::Sell));
+        assert_eq!(btc.trade_mode, Some(TradeMode::Cross));
+        assert!(btc.auto_add_margin);
+        assert_eq!(btc.position_status, PositionStatus::Normal);
+        assert_eq!(btc.liq_price, None);
+        assert_eq!(btc.position_im, Some("0.00010923".parse()?));
+        assert_eq!(btc.adl_rank_indicator, 2);
+
+        let eth = &page.list[1];
+        assert_eq!(eth.side, None);
+        assert_eq!(eth.leverage, None);
+        assert_eq!(eth.created_time, None);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn switch_mode() {
+        let req = SwitchModeRequest {
+            category: Product::UsdMFutures,
+            coin: Some("USDT".into()),
+            mode: PositionMode::Hedge,
+            ..Default::default()
+        };
+        assert_eq!(
+            SwitchModeRequest::BODY.encode(&req)?,
+            r#"{"category":"linear","coin":"USDT","mode":3}"#
+        );
+
+        let req = SetTpSlModeRequest {
+            category: Product::UsdMFutures,
+            symbol: "XRPUSDT".into(),
+            tp_sl_mode: TpSlMode::Partial,
+        };
+        assert_eq!(
+            SetTpSlModeRequest::BODY.encode(&req)?,
+            r#"{"category":"linear","symbol":"XRPUSDT","tpSlMode":"Partial"}"#
+        );
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn trading_stop() {
+        let req = TradingStopRequest {
+            category: Product::UsdMFutures,
+            symbol: "XRPUSDT".into(),
+            tpsl_mode: TpSlMode::Partial,
+            position_idx: PositionIdx::HedgeBuy,
+            take_profit: Some("0.6".parse()?),
+            tp_size: Some("50".parse()?),
+            tp_order_type: Some(OrderType::Limit),
+            tp_limit_price: Some("0.57".parse()?),
+            ..Default::default()
+        };
+        req.validate()?;
+        assert_eq!(
+            TradingStopRequest::BODY.encode(&req)?,
+            r#"{"category":"linear","symbol":"XRPUSDT","tpslMode":"Partial","positionIdx":1,"takeProfit":"0.6","tpSize":"50","tpLimitPrice":"0.57","tpOrderType":"Limit"}"#
+        );
+
+        let full = TradingStopRequest {
+            tpsl_mode: TpSlMode::Full,
+            ..req
+        };
+        assert!(full.validate().is_err());
+
+        let req = SetAutoAddMarginRequest {
+            category: Product::UsdMFutures,
+            symbol: "BTCUSDT".into(),
+            auto_add_margin: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            SetAutoAddMarginRequest::BODY.encode(&req)?,
+            r#"{"category":"linear","symbol":"BTCUSDT","autoAddMargin":1}"#
+        );
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn closed_pnl() {
+        let page: Page<ClosedPnl> = from_str(
+            r#"{"nextPageCursor":"5a373bfe-188d-4913-9c81-d57ab5be8068%3A1672214887231423699","category":"linear","list":[{"symbol":"ETHPERP","orderType":"Market","leverage":"3","updatedTime":"1672214887236","side":"Sell","orderId":"5a373bfe-188d-4913-9c81-d57ab5be8068","closedPnl":"-47.4065323","avgEntryPrice":"1194.97516667","qty":"3","cumEntryValue":"3584.9255","createdTime":"1672214887231","orderPrice":"1122.95","closedSize":"3","avgExitPrice":"1180.59833333","execType":"Trade","fillCount":"4","cumExitValue":"3541.795","openFee":"","closeFee":""}]}"#,
+        )?;
+        let pnl = &page.list[0];
+        assert_eq!(pnl.closed_pnl, "-47.4065323".parse()?);
+        assert_eq!(pnl.exec_type, ExecutionType::Trade);
+        assert_eq!(pnl.open_fee, None);
+        assert_eq!(pnl.created_time.timestamp_millis(), 1672214887231);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn move_positions() {
+        let req = MovePositionsRequest {
+            from_uid: "100307601".into(),
+            to_uid: "592324".into(),
+            list: vec![MovePosition {
+                category: Product::Spot,
+                symbol: "BTCUSDT".into(),
+                price: "100".parse()?,
+                side: Side::Sell,
+                qty: "0.01".parse()?,
+            }],
+        };
+        assert_eq!(
+            MovePositionsRequest::BODY.encode(&req)?,
+            r#"{"fromUid":"100307601","toUid":"592324","list":[{"category":"spot","symbol":"BTCUSDT","price":"100","side":"Sell","qty":"0.01"}]}"#
+        );
+
+        let resp: MovePositionsResponse = from_str(
+            r#"{"blockTradeId":"e9bb926c95f54cf1ba3e315a58b8597b","status":"Processing","rejectParty":""}"#,
+        )?;
+        assert_eq!(resp.status, MovePositionStatus::Processing);
+
+        let page: Page<MovePositionRecord> = from_str(
+            r#"{"list":[{"blockTradeId":"1a82e5801af74b67b7ad71ba00a7391a","category":"option","orderId":"8e09c5b8-f651-4cec-968d-52764cac11ec","userId":592324,"symbol":"BTC-14MAY24-60000-C","side":"Buy","price":"0.01","qty":"1","execFee":"0","status":"Filled","execId":"ae5d0fc2-d1a2-4dba-9c0a-a1c7a1b8f7e2","resultCode":0,"resultMessage":"","createdAt":1715246410566,"updatedAt":1715246410744,"rejectParty":""}],"nextPageCursor":"page_token%3D1241742%26"}"#,
+        )?;
+        assert_eq!(page.list[0].status, MovePositionStatus::Filled);
+        assert_eq!(page.list[0].created_at.timestamp_millis(), 1715246410566);
+    }
+}
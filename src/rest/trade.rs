@@ -1,111 +1,815 @@
-use crate::models::order::{CancelOrderResponse, CanceledOrder, NewOrderResponse};
+use crate::error::{BybitError, BybitResponseError};
+use crate::models::order::{BatchOrder, BatchOrders, Execution, Order, OrderIds};
 use crate::models::{
-    NewOrderResponseType, OrderType, PositionSide, Product, Side, TimeInForce, WorkingType,
+    DcpProduct, ExecutionType, MarketUnit, OrderFilter, OrderRef, OrderStatus, OrderType, Page,
+    PositionIdx, Product, Side, SmpType, TimeInForce, TpSlMode, TriggerBy, TriggerDirection,
 };
-use crate::parser::string_or;
-use fehler::throw;
+use crate::rest::Request;
+use crate::Bybit;
+use chrono::serde::ts_milliseconds_option;
+use chrono::{DateTime, Utc};
+use fehler::{throw, throws};
 use reqwest::Method;
 use rust_decimal::Decimal;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+use serde_with::{As, BoolFromInt};
 
+// https://bybit-exchange.github.io/docs/v5/order/create-order
 crate::define_request! {
-    Name => NewOrder;
-    Product => Product::UsdMFutures;
+    Name => CreateOrder;
     Method => Method::POST;
-    Endpoint => "/fapi/v1/order";
+    Endpoint => "/v5/order/create";
     Signed => true;
     Request => {
+        pub category: Product,
         pub symbol: String,
+        /// Spot only, whether to borrow on margin
+        #[serde(with = "As::<Option<BoolFromInt>>")]
+        pub is_leverage: Option<bool>,
         pub side: Side,
-        pub position_side: Option<PositionSide>,
-        pub r#type: OrderType,
+        pub order_type: OrderType,
+        pub qty: Decimal,
+        /// Spot market orders only, the unit of `qty`
+        pub market_unit: Option<MarketUnit>,
+        pub price: Option<Decimal>,
+        /// Makes a conditional order together with `trigger_price`
+        pub trigger_direction: Option<TriggerDirection>,
+        /// Spot only
+        pub order_filter: Option<OrderFilter>,
+        pub trigger_price: Option<Decimal>,
+        pub trigger_by: Option<TriggerBy>,
+        /// Option only, takes precedence over `price`
+        pub order_iv: Option<Decimal>,
         pub time_in_force: Option<TimeInForce>,
-        pub quantity: Option<Decimal>,
+        /// Required in hedge mode
+        pub position_idx: Option<PositionIdx>,
+        pub order_link_id: Option<String>,
+        pub take_profit: Option<Decimal>,
+        pub stop_loss: Option<Decimal>,
+        pub tp_trigger_by: Option<TriggerBy>,
+        pub sl_trigger_by: Option<TriggerBy>,
         pub reduce_only: Option<bool>,
+        pub close_on_trigger: Option<bool>,
+        pub smp_type: Option<SmpType>,
+        /// Option only, market maker protection
+        pub mmp: Option<bool>,
+        pub tpsl_mode: Option<TpSlMode>,
+        /// Only with `tp_order_type` `Limit`, and `TpSlMode::Partial` for derivatives
+        pub tp_limit_price: Option<Decimal>,
+        /// Only with `sl_order_type` `Limit`, and `TpSlMode::Partial` for derivatives
+        pub sl_limit_price: Option<Decimal>,
+        pub tp_order_type: Option<OrderType>,
+        pub sl_order_type: Option<OrderType>,
+    };
+    Response => OrderIds;
+    Validate => validate_create_order;
+}
+
+#[throws(BybitError)]
+fn validate_create_order(req: &CreateOrderRequest) {
+    check_create_order(req.category, req)?;
+}
+
+// Batch items are validated against the batch's category, which replaces their own
+#[throws(BybitError)]
+fn check_create_order(category: Product, req: &CreateOrderRequest) {
+    check_tpsl_limit_price(
+        "tp",
+        category,
+        req.tpsl_mode,
+        req.tp_order_type,
+        req.tp_limit_price,
+    )?;
+    check_tpsl_limit_price(
+        "sl",
+        category,
+        req.tpsl_mode,
+        req.sl_order_type,
+        req.sl_limit_price,
+    )?;
+}
+
+/// Limit prices of TP/SL are only taken with a `Limit` TP/SL order type and, for derivatives,
+/// in partial mode. Spot has no TP/SL mode.
+#[throws(BybitError)]
+pub(crate) fn check_tpsl_limit_price(
+    kind: &str,
+    category: Product,
+    tpsl_mode: Option<TpSlMode>,
+    order_type: Option<OrderType>,
+    limit_price: Option<Decimal>,
+) {
+    if limit_price.is_none() {
+        return;
+    }
+    if order_type != Some(OrderType::Limit) {
+        throw!(BybitError::InvalidRequest(format!(
+            "{kind}_limit_price requires a Limit {kind}_order_type"
+        )))
+    }
+    check_tpsl_partial(kind, category, tpsl_mode)?;
+}
+
+#[throws(BybitError)]
+fn check_tpsl_partial(kind: &str, category: Product, tpsl_mode: Option<TpSlMode>) {
+    if category != Product::Spot && tpsl_mode != Some(TpSlMode::Partial) {
+        throw!(BybitError::InvalidRequest(format!(
+            "{kind}_limit_price requires TpSlMode::Partial"
+        )))
+    }
+}
+
+// Only unfilled or partially filled orders can be amended, fields left `None` keep their value.
+crate::define_request! {
+    Name => AmendOrder;
+    Method => Method::POST;
+    Endpoint => "/v5/order/amend";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: String,
+        #[serde(flatten)]
+        pub order: OrderRef,
+        /// Option only
+        pub order_iv: Option<Decimal>,
+        pub trigger_price: Option<Decimal>,
+        pub qty: Option<Decimal>,
         pub price: Option<Decimal>,
-        pub new_client_order_id: Option<String>,
-        pub stop_price: Option<Decimal>,
-        pub close_position: Option<bool>,
-        pub activation_price: Option<Decimal>,
-        pub callback_rate: Option<Decimal>,
-        pub working_type: Option<WorkingType>,
-        pub price_protect: Option<Decimal>,
-        pub new_order_resp_type: Option<NewOrderResponseType>,
+        pub tpsl_mode: Option<TpSlMode>,
+        /// `0` cancels the take profit
+        pub take_profit: Option<Decimal>,
+        /// `0` cancels the stop loss
+        pub stop_loss: Option<Decimal>,
+        pub tp_trigger_by: Option<TriggerBy>,
+        pub sl_trigger_by: Option<TriggerBy>,
+        pub trigger_by: Option<TriggerBy>,
+        pub tp_limit_price: Option<Decimal>,
+        pub sl_limit_price: Option<Decimal>,
     };
-    Response => NewOrderResponse;
+    Response => OrderIds;
+    Validate => validate_amend_order;
+}
+
+#[throws(BybitError)]
+fn validate_amend_order(req: &AmendOrderRequest) {
+    check_amend_order(req.category, req)?;
+}
+
+#[throws(BybitError)]
+fn check_amend_order(category: Product, req: &AmendOrderRequest) {
+    check_order_ref(&req.order)?;
+    // The TP/SL order type can't be amended, so only the mode is checked
+    for (kind, limit_price) in [("tp", req.tp_limit_price), ("sl", req.sl_limit_price)] {
+        if limit_price.is_some() {
+            check_tpsl_partial(kind, category, req.tpsl_mode)?;
+        }
+    }
+}
+
+/// `OrderRef::default()` has an empty id, which Bybit would not match to any order.
+#[throws(BybitError)]
+fn check_order_ref(order: &OrderRef) {
+    let (OrderRef::OrderId(id) | OrderRef::OrderLinkId(id)) = order;
+    if id.is_empty() {
+        throw!(BybitError::InvalidRequest(
+            "orderId or orderLinkId is required".into()
+        ))
+    }
 }
 
 crate::define_request! {
     Name => CancelOrder;
-    Product => Product::UsdMFutures;
-    Method => Method::DELETE;
-    Endpoint => "/fapi/v1/order";
+    Method => Method::POST;
+    Endpoint => "/v5/order/cancel";
     Signed => true;
     Request => {
+        pub category: Product,
         pub symbol: String,
-        pub order_id: Option<u64>,
-        pub orig_client_order_id: Option<String>
+        #[serde(flatten)]
+        pub order: OrderRef,
+        /// Spot only, defaults to `Order`
+        pub order_filter: Option<OrderFilter>,
     };
-    Response => CancelOrderResponse;
+    Response => OrderIds;
+    Validate => validate_cancel_order;
 }
 
+#[throws(BybitError)]
+fn validate_cancel_order(req: &CancelOrderRequest) {
+    check_order_ref(&req.order)?;
+}
+
+// Without `symbol`, linear and inverse require `base_coin` or `settle_coin`.
+// Cancelling by `settle_coin` cancels every order margined in that coin.
 crate::define_request! {
-    Name => CancelMultipleOrders;
-    Product => Product::UsdMFutures;
-    Method => Method::DELETE;
-    Endpoint => "/fapi/v1/batchOrders";
+    Name => CancelAllOrders;
+    Method => Method::POST;
+    Endpoint => "/v5/order/cancel-all";
     Signed => true;
     Request => {
-        pub symbol: String,
-        pub order_id_list: Vec<u64>,
-        pub orig_client_order_id_list: Vec<String>,
+        pub category: Product,
+        pub symbol: Option<String>,
+        pub base_coin: Option<String>,
+        pub settle_coin: Option<String>,
+        pub order_filter: Option<OrderFilter>,
+        /// `Stop` only cancels conditional orders
+        pub stop_order_type: Option<OrderType>,
+    };
+    Response => {
+        pub list: Vec<OrderIds>,
     };
-    Response => Vec<CanceledOrder>;
 }
 
+// Batch endpoints take up to 20 items for linear, inverse and option, and 10 for spot.
+// The items are the single order requests, their `category` is replaced by the batch's one.
+// Send them with `Bybit::batch` to get the outcome of every item.
 crate::define_request! {
-    Name => CancelAllOpenOrders;
-    Product => Product::UsdMFutures;
-    Method => Method::DELETE;
-    Endpoint => "/fapi/v1/allOpenOrders";
+    Name => BatchCreateOrder;
+    Method => Method::POST;
+    Endpoint => "/v5/order/create-batch";
     Signed => true;
     Request => {
-        pub symbol: String,
+        pub category: Product,
+        #[serde(serialize_with = "without_category")]
+        pub request: Vec<CreateOrderRequest>,
+    };
+    Response => BatchOrders;
+    Validate => validate_batch_create_order;
+}
+
+#[throws(BybitError)]
+fn validate_batch_create_order(req: &BatchCreateOrderRequest) {
+    req.request
+        .iter()
+        .try_for_each(|item| check_create_order(req.category, item))?
+}
+
+crate::define_request! {
+    Name => BatchAmendOrder;
+    Method => Method::POST;
+    Endpoint => "/v5/order/amend-batch";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        #[serde(serialize_with = "without_category")]
+        pub request: Vec<AmendOrderRequest>,
+    };
+    Response => BatchOrders;
+    Validate => validate_batch_amend_order;
+}
+
+#[throws(BybitError)]
+fn validate_batch_amend_order(req: &BatchAmendOrderRequest) {
+    req.request
+        .iter()
+        .try_for_each(|item| check_amend_order(req.category, item))?
+}
+
+crate::define_request! {
+    Name => BatchCancelOrder;
+    Method => Method::POST;
+    Endpoint => "/v5/order/cancel-batch";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        #[serde(serialize_with = "without_category")]
+        pub request: Vec<CancelOrderRequest>,
     };
-    Response => CancelAllOpenOrdersResponse;
+    Response => BatchOrders;
+    Validate => validate_batch_cancel_order;
+}
+
+#[throws(BybitError)]
+fn validate_batch_cancel_order(req: &BatchCancelOrderRequest) {
+    req.request.iter().try_for_each(validate_cancel_order)?
 }
 
-#[derive(Deserialize, Debug, Clone)]
-pub struct CancelAllOpenOrdersResponse {
-    #[serde(deserialize_with = "check")]
-    pub code: u64,
-    pub msg: String,
+fn without_category<T: Serialize, S: Serializer>(
+    items: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let items = items
+        .iter()
+        .map(|item| {
+            let mut item = serde_json::to_value(item).map_err(serde::ser::Error::custom)?;
+            if let Value::Object(fields) = &mut item {
+                fields.remove("category");
+            }
+            Ok(item)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    items.serialize(serializer)
 }
 
-pub fn check<'de, D>(d: D) -> Result<u64, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let v = u64::deserialize(d)?;
-    if v != 200 {
-        throw!(serde::de::Error::custom("not success code"))
+#[derive(Deserialize, Default)]
+struct BatchExtInfo {
+    #[serde(default)]
+    list: Vec<BatchItemStatus>,
+}
+
+#[derive(Deserialize)]
+struct BatchItemStatus {
+    code: i64,
+    msg: String,
+}
+
+impl Bybit {
+    /// Sends a batch request and returns the outcome of every item, in the order they were
+    /// submitted. Failed items carry their own `BybitError::BybitResponse`.
+    #[throws(BybitError)]
+    pub async fn batch<R>(&self, req: R) -> Vec<Result<BatchOrder, BybitError>>
+    where
+        R: Request<Response = BatchOrders>,
+    {
+        let (orders, ext_info) = self.request_with_ext_info(req).await?;
+        pair_batch_results(orders, ext_info)?
     }
-    Ok(v)
 }
 
+#[throws(BybitError)]
+fn pair_batch_results(orders: BatchOrders, ext_info: Value) -> Vec<Result<BatchOrder, BybitError>> {
+    // `retExtInfo` is `{}` when the batch itself is accepted without per-item details
+    let statuses: BatchExtInfo = match ext_info {
+        Value::Null => BatchExtInfo::default(),
+        ext_info => serde_json::from_value(ext_info)?,
+    };
+    let mut statuses = statuses.list.into_iter();
+    orders
+        .list
+        .into_iter()
+        .map(|order| match statuses.next() {
+            Some(status) if status.code != 0 => Err(BybitResponseError {
+                ret_code: status.code,
+                ret_msg: status.msg,
+                ret_ext_info: Value::Null,
+                time: 0,
+            }
+            .into()),
+            _ => Ok(order),
+        })
+        .collect()
+}
+
+// Open orders, plus recently closed ones with `open_only: Some(1)`. Linear and inverse need
+// `symbol`, `base_coin` or `settle_coin`. Use `Bybit::paginate` to stream every order.
+crate::define_request! {
+    Name => OpenOrders;
+    Method => Method::GET;
+    Endpoint => "/v5/order/realtime";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: Option<String>,
+        pub base_coin: Option<String>,
+        pub settle_coin: Option<String>,
+        pub order_id: Option<String>,
+        pub order_link_id: Option<String>,
+        pub open_only: Option<u8>,
+        pub order_filter: Option<OrderFilter>,
+        pub limit: Option<u64>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<Order>;
+}
+
+crate::impl_cursor_request!(OpenOrdersRequest => Order);
+
+// Closed orders of the last 2 years, a time range spans at most 7 days and defaults to the
+// last 7 days. Use `Bybit::paginate` to stream every order.
 crate::define_request! {
-    Name => AutoCancelAllOpenOrders;
-    Product => Product::UsdMFutures;
+    Name => OrderHistory;
+    Method => Method::GET;
+    Endpoint => "/v5/order/history";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: Option<String>,
+        pub base_coin: Option<String>,
+        pub settle_coin: Option<String>,
+        pub order_id: Option<String>,
+        pub order_link_id: Option<String>,
+        pub order_filter: Option<OrderFilter>,
+        pub order_status: Option<OrderStatus>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start_time: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end_time: Option<DateTime<Utc>>,
+        pub limit: Option<u64>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<Order>;
+}
+
+crate::impl_cursor_request!(OrderHistoryRequest => Order);
+
+// A time range spans at most 7 days and defaults to the last 7 days.
+// Use `Bybit::paginate` to stream every execution.
+crate::define_request! {
+    Name => Executions;
+    Method => Method::GET;
+    Endpoint => "/v5/execution/list";
+    Signed => true;
+    Request => {
+        pub category: Product,
+        pub symbol: Option<String>,
+        pub order_id: Option<String>,
+        pub order_link_id: Option<String>,
+        pub base_coin: Option<String>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub start_time: Option<DateTime<Utc>>,
+        #[serde(with = "ts_milliseconds_option")]
+        pub end_time: Option<DateTime<Utc>>,
+        pub exec_type: Option<ExecutionType>,
+        pub limit: Option<u64>,
+        pub cursor: Option<String>,
+    };
+    Response => Page<Execution>;
+}
+
+crate::impl_cursor_request!(ExecutionsRequest => Execution);
+
+// Disconnection protection (DCP): cancels every order of `product` when no private websocket
+// of the account stays connected for `time_window` seconds, between 3 and 300.
+// DCP has to be enabled for the account first.
+crate::define_request! {
+    Name => DisconnectedCancelAll;
     Method => Method::POST;
-    Endpoint => "/fapi/v1/countdownCancelAll";
+    Endpoint => "/v5/order/disconnected-cancel-all";
     Signed => true;
     Request => {
-        pub symbol: String,
-        pub countdown_time: u64,
+        /// Defaults to `Options`
+        pub product: Option<DcpProduct>,
+        pub time_window: u64,
     };
-    Response => {
-        pub symbol: String,
-        #[serde(with="string_or")]
-        pub countdown_time: u64,
+    Response => {};
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        pair_batch_results, AmendOrderRequest, BatchAmendOrderRequest, BatchCancelOrderRequest,
+        BatchCreateOrderRequest, CancelAllOrdersRequest, CancelAllOrdersResponse,
+        CancelOrderRequest, CreateOrderRequest, DisconnectedCancelAllRequest,
     };
+    use crate::error::{BybitError, BybitErrorCode};
+    use crate::models::order::{BatchOrders, Execution, Order, OrderIds};
+    use crate::models::{
+        DcpProduct, ExecutionType, MarketUnit, OrderFilter, OrderRef, OrderStatus, OrderType, Page,
+        PositionIdx, Product, Side, TimeInForce, TpSlMode, TriggerBy, TriggerDirection,
+    };
+    use crate::rest::Request;
+    use anyhow::Error;
+    use fehler::throws;
+    use serde_json::{from_str, json, Value};
+
+    #[throws(Error)]
+    #[test]
+    fn create_order() {
+        let req = CreateOrderRequest {
+            category: Product::UsdMFutures,
+            symbol: "BTCUSDT".into(),
+            side: Side::Buy,
+            order_type: OrderType::Limit,
+            qty: "0.01".parse()?,
+            price: Some("60000".parse()?),
+            time_in_force: Some(TimeInForce::PostOnly),
+            position_idx: Some(PositionIdx::HedgeBuy),
+            order_link_id: Some("bybit-async-rs".into()),
+            take_profit: Some("70000".parse()?),
+            tp_trigger_by: Some(TriggerBy::MarkPrice),
+            tpsl_mode: Some(TpSlMode::Partial),
+            tp_order_type: Some(OrderType::Limit),
+            tp_limit_price: Some("69900".parse()?),
+            reduce_only: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(
+            CreateOrderRequest::BODY.encode(&req)?,
+            r#"{"category":"linear","symbol":"BTCUSDT","side":"Buy","orderType":"Limit","qty":"0.01","price":"60000","timeInForce":"PostOnly","positionIdx":1,"orderLinkId":"bybit-async-rs","takeProfit":"70000","tpTriggerBy":"MarkPrice","reduceOnly":false,"tpslMode":"Partial","tpLimitPrice":"69900","tpOrderType":"Limit"}"#
+        );
+
+        let req = CreateOrderRequest {
+            category: Product::Spot,
+            symbol: "BTCUSDT".into(),
+            is_leverage: Some(true),
+            qty: "100".parse()?,
+            market_unit: Some(MarketUnit::QuoteCoin),
+            ..Default::default()
+        };
+        assert_eq!(
+            CreateOrderRequest::BODY.encode(&req)?,
+            r#"{"category":"spot","symbol":"BTCUSDT","isLeverage":1,"side":"Buy","orderType":"Market","qty":"100","marketUnit":"quoteCoin"}"#
+        );
+
+        let resp: OrderIds =
+            from_str(r#"{"orderId":"1321003749386327552","orderLinkId":"spot-test-postonly"}"#)?;
+        assert_eq!(resp.order_id, "1321003749386327552");
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn amend_order() {
+        let req = AmendOrderRequest {
+            category: Product::UsdMFutures,
+            symbol: "ETHPERP".into(),
+            order: OrderRef::OrderLinkId("bybit-async-rs".into()),
+            price: Some("1500".parse()?),
+            stop_loss: Some("0".parse()?),
+            sl_trigger_by: Some(TriggerBy::IndexPrice),
+            ..Default::default()
+        };
+        let body = AmendOrderRequest::BODY.encode(&req)?;
+        assert_eq!(
+            body,
+            r#"{"category":"linear","symbol":"ETHPERP","orderLinkId":"bybit-async-rs","price":"1500","stopLoss":"0","slTriggerBy":"IndexPrice"}"#
+        );
+        assert_eq!(from_str::<AmendOrderRequest>(&body)?.order, req.order);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn cancel_all_orders() {
+        let req = CancelAllOrdersRequest {
+            category: Product::UsdMFutures,
+            settle_coin: Some("USDT".into()),
+            order_filter: Some(OrderFilter::StopOrder),
+            ..Default::default()
+        };
+        assert_eq!(
+            CancelAllOrdersRequest::BODY.encode(&req)?,
+            r#"{"category":"linear","settleCoin":"USDT","orderFilter":"StopOrder"}"#
+        );
+
+        let resp: CancelAllOrdersResponse = from_str(
+            r#"{"list":[{"orderId":"1616024329462743808","orderLinkId":"1616024329462743809"},{"orderId":"1616024287544869632","orderLinkId":"1616024287544869633"}],"success":"1"}"#,
+        )?;
+        assert_eq!(resp.list.len(), 2);
+        assert_eq!(resp.list[1].order_link_id, "1616024287544869633");
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn batch_cancel_order() {
+        let req = BatchCancelOrderRequest {
+            category: Product::Spot,
+            request: vec![
+                CancelOrderRequest {
+                    symbol: "BTCUSDT".into(),
+                    order: OrderRef::OrderId("1666800494330512128".into()),
+                    ..Default::default()
+                },
+                CancelOrderRequest {
+                    symbol: "ATOMUSDT".into(),
+                    order: OrderRef::OrderLinkId("1666800494330512129".into()),
+                    ..Default::default()
+                },
+            ],
+        };
+        let body: Value = from_str(&BatchCancelOrderRequest::BODY.encode(&req)?)?;
+        assert_eq!(
+            body,
+            json!({
+                "category": "spot",
+                "request": [
+                    {"symbol": "BTCUSDT", "orderId": "1666800494330512128"},
+                    {"symbol": "ATOMUSDT", "orderLinkId": "1666800494330512129"},
+                ],
+            })
+        );
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn batch_results() {
+        let orders: BatchOrders = from_str(
+            r#"{"list":[{"category":"option","symbol":"ETH-26DEC24-3000-C","orderId":"b0a0a3d6-87d6-4c4f-a4a2-12d3b4a5c6d7","orderLinkId":"quote-1","createAt":"1719540239211"},{"category":"option","symbol":"ETH-26DEC24-3500-C","orderId":"","orderLinkId":"quote-2","createAt":""}]}"#,
+        )?;
+        let ext_info = serde_json::from_str(
+            r#"{"list":[{"code":0,"msg":"OK"},{"code":110007,"msg":"Insufficient available balance"}]}"#,
+        )?;
+        let results = pair_batch_results(orders, ext_info)?;
+        assert_eq!(results.len(), 2);
+        let order = results[0].as_ref().unwrap();
+        assert_eq!(order.order_link_id, "quote-1");
+        assert_eq!(
+            order.create_at.map(|t| t.timestamp_millis()),
+            Some(1719540239211)
+        );
+        assert_eq!(
+            results[1].as_ref().unwrap_err().code(),
+            Some(BybitErrorCode::AvailableBalanceNotEnough)
+        );
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn open_orders() {
+        let page: Page<Order> = from_str(
+            r#"{"category":"linear","nextPageCursor":"page_args%3Dfd4300ae-7847-404e-b947-b46980a4d140%26symbol%3D6%26","list":[{"orderId":"fd4300ae-7847-404e-b947-b46980a4d140","orderLinkId":"test-000005","blockTradeId":"","symbol":"ETHUSDT","price":"1600.00","qty":"0.10","side":"Buy","isLeverage":"","positionIdx":1,"orderStatus":"New","cancelType":"UNKNOWN","rejectReason":"EC_NoError","avgPrice":"0","leavesQty":"0.10","leavesValue":"160","cumExecQty":"0.00","cumExecValue":"0","cumExecFee":"0","timeInForce":"GTC","orderType":"Limit","stopOrderType":"UNKNOWN","orderIv":"","triggerPrice":"0.00","takeProfit":"2500.00","stopLoss":"1500.00","tpTriggerBy":"LastPrice","slTriggerBy":"LastPrice","triggerDirection":0,"triggerBy":"UNKNOWN","lastPriceOnCreated":"","reduceOnly":false,"closeOnTrigger":false,"smpType":"None","smpGroup":0,"smpOrderId":"","tpslMode":"Full","tpLimitPrice":"","slLimitPrice":"","placeType":"","createdTime":"1684738540559","updatedTime":"1684738540561"}]}"#,
+        )?;
+        let order = &page.list[0];
+        assert_eq!(order.position_idx, PositionIdx::HedgeBuy);
+        assert_eq!(order.order_status, OrderStatus::New);
+        assert_eq!(order.take_profit, Some("2500".parse()?));
+        assert_eq!(order.tpsl_mode, Some(TpSlMode::Full));
+        assert_eq!(order.trigger_direction, None);
+        assert_eq!(order.order_iv, None);
+        assert_eq!(order.created_time.timestamp_millis(), 1684738540559);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn user_order_update() {
+        let orders: Vec<Order> = from_str(
+            r#"[{"category":"option","symbol":"ETH-30DEC22-1400-C","orderId":"5cf98b1d-0b3e-4d3c-9d9a-d2e0d3ff0d2e","orderLinkId":"","blockTradeId":"","side":"Sell","positionIdx":0,"orderStatus":"Untriggered","cancelType":"UNKNOWN","rejectReason":"EC_NoError","timeInForce":"IOC","isLeverage":"","price":"0","qty":"0.10","avgPrice":"","leavesQty":"0.10","leavesValue":"0","cumExecQty":"0.00","cumExecValue":"0","cumExecFee":"0","orderType":"Market","stopOrderType":"StopLoss","orderIv":"","triggerPrice":"1500","takeProfit":"","stopLoss":"","triggerBy":"MarkPrice","tpTriggerBy":"","slTriggerBy":"","triggerDirection":2,"placeType":"price","lastPriceOnCreated":"1600","closeOnTrigger":false,"reduceOnly":true,"smpGroup":0,"smpType":"None","smpOrderId":"","slLimitPrice":"0","tpLimitPrice":"0","marketUnit":"","createdTime":"1672364262444","updatedTime":"1672364262457","feeCurrency":""}]"#,
+        )?;
+        let order = &orders[0];
+        assert_eq!(order.category, Some(Product::EuropeanOptions));
+        assert_eq!(order.stop_order_type, Some(OrderType::StopLoss));
+        assert_eq!(order.trigger_by, Some(TriggerBy::MarkPrice));
+        assert_eq!(order.trigger_direction, Some(TriggerDirection::Fall));
+        assert_eq!(order.avg_price, None);
+        assert_eq!(order.market_unit, None);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn executions() {
+        let page: Page<Execution> = from_str(
+            r#"{"nextPageCursor":"132766%3A2%2C132766%3A2","category":"linear","list":[{"symbol":"ETHPERP","orderType":"Market","underlyingPrice":"","orderLinkId":"","side":"Buy","indexPrice":"","orderId":"8c065341-7b52-4ca9-ac2c-37e31ac55c94","stopOrderType":"UNKNOWN","leavesQty":"0","execTime":"1672282722429","feeCurrency":"","isMaker":false,"execFee":"0.071409","feeRate":"0.0006","execId":"e0cbe81d-0f18-5866-9415-cf319b5dab3b","tradeIv":"","blockTradeId":"","markPrice":"1183.54","execPrice":"1190.15","markIv":"","orderQty":"0.1","orderPrice":"1236.9","execValue":"119.015","execType":"Trade","execQty":"0.1","closedSize":"","seq":4688002127}]}"#,
+        )?;
+        let exec = &page.list[0];
+        assert_eq!(exec.exec_type, ExecutionType::Trade);
+        assert!(!exec.is_maker);
+        assert_eq!(exec.exec_fee, "0.071409".parse()?);
+        assert_eq!(exec.mark_price, Some("1183.54".parse()?));
+        assert_eq!(exec.index_price, None);
+        assert_eq!(exec.stop_order_type, None);
+        assert_eq!(exec.exec_time.timestamp_millis(), 1672282722429);
+        assert_eq!(exec.order_type, Some(OrderType::Market));
+        assert_eq!(exec.order_qty, Some("0.1".parse()?));
+
+        // Funding has no order behind it
+        let page: Page<Execution> = from_str(
+            r#"{"nextPageCursor":"","category":"linear","list":[{"symbol":"BTCUSDT","orderType":"UNKNOWN","underlyingPrice":"","orderLinkId":"","side":"Sell","indexPrice":"","orderId":"","stopOrderType":"UNKNOWN","leavesQty":"","execTime":"1672300800000","feeCurrency":"","isMaker":false,"execFee":"-0.0116","feeRate":"0.0001","execId":"7d3c8f6a-1f2e-4b5c-9a8d-0e1f2a3b4c5d","tradeIv":"","blockTradeId":"","markPrice":"","execPrice":"16553.5","markIv":"","orderQty":"","orderPrice":"","execValue":"116.0","execType":"Funding","execQty":"0.007","closedSize":"","seq":4688002128}]}"#,
+        )?;
+        let exec = &page.list[0];
+        assert_eq!(exec.exec_type, ExecutionType::Funding);
+        assert_eq!(exec.order_type, None);
+        assert_eq!(exec.order_price, None);
+        assert_eq!(exec.order_qty, None);
+        assert_eq!(exec.exec_fee, "-0.0116".parse()?);
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn disconnected_cancel_all() {
+        let req = DisconnectedCancelAllRequest {
+            product: Some(DcpProduct::Derivatives),
+            time_window: 40,
+        };
+        assert_eq!(
+            DisconnectedCancelAllRequest::BODY.encode(&req)?,
+            r#"{"product":"DERIVATIVES","timeWindow":40}"#
+        );
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn empty_order_ref() {
+        let req = CancelOrderRequest {
+            category: Product::UsdMFutures,
+            symbol: "BTCUSDT".into(),
+            ..Default::default()
+        };
+        assert!(matches!(req.validate(), Err(BybitError::InvalidRequest(_))));
+
+        let amend = AmendOrderRequest {
+            category: Product::UsdMFutures,
+            symbol: "BTCUSDT".into(),
+            order: OrderRef::OrderLinkId(String::new()),
+            ..Default::default()
+        };
+        assert!(amend.validate().is_err());
+
+        let linked = CancelOrderRequest {
+            order: OrderRef::OrderLinkId("bybit-async-rs".into()),
+            ..req.clone()
+        };
+        linked.validate()?;
+
+        let batch = BatchCancelOrderRequest {
+            category: Product::UsdMFutures,
+            request: vec![linked, req],
+        };
+        assert!(batch.validate().is_err());
+
+        let batch = BatchAmendOrderRequest {
+            category: Product::UsdMFutures,
+            request: vec![amend],
+        };
+        assert!(batch.validate().is_err());
+    }
+
+    #[throws(Error)]
+    #[test]
+    fn tpsl_limit_price() {
+        let partial = CreateOrderRequest {
+            category: Product::UsdMFutures,
+            symbol: "BTCUSDT".into(),
+            qty: "0.01".parse()?,
+            tpsl_mode: Some(TpSlMode::Partial),
+            take_profit: Some("70000".parse()?),
+            tp_order_type: Some(OrderType::Limit),
+            tp_limit_price: Some("69900".parse()?),
+            ..Default::default()
+        };
+        partial.validate()?;
+
+        let full = CreateOrderRequest {
+            tpsl_mode: Some(TpSlMode::Full),
+            ..partial.clone()
+        };
+        assert!(matches!(
+            full.validate(),
+            Err(BybitError::InvalidRequest(_))
+        ));
+
+        let market = CreateOrderRequest {
+            sl_limit_price: Some("50100".parse()?),
+            sl_order_type: Some(OrderType::Market),
+            ..partial.clone()
+        };
+        assert!(market.validate().is_err());
+
+        let batch = BatchCreateOrderRequest {
+            category: Product::UsdMFutures,
+            request: vec![partial, market],
+        };
+        assert!(batch.validate().is_err());
+
+        let amend = AmendOrderRequest {
+            category: Product::UsdMFutures,
+            symbol: "BTCUSDT".into(),
+            order: OrderRef::OrderId("1321003749386327552".into()),
+            tpsl_mode: Some(TpSlMode::Partial),
+            sl_limit_price: Some("50100".parse()?),
+            ..Default::default()
+        };
+        amend.validate()?;
+
+        let unset = AmendOrderRequest {
+            tpsl_mode: None,
+            ..amend.clone()
+        };
+        assert!(matches!(
+            unset.validate(),
+            Err(BybitError::InvalidRequest(_))
+        ));
+
+        let batch = BatchAmendOrderRequest {
+            category: Product::UsdMFutures,
+            request: vec![amend, unset.clone()],
+        };
+        assert!(batch.validate().is_err());
+
+        // Spot has no TP/SL mode, only the order type is required
+        let spot = CreateOrderRequest {
+            category: Product::Spot,
+            symbol: "BTCUSDT".into(),
+            qty: "0.01".parse()?,
+            take_profit: Some("70000".parse()?),
+            tp_order_type: Some(OrderType::Limit),
+            tp_limit_price: Some("69900".parse()?),
+            ..Default::default()
+        };
+        spot.validate()?;
+        let spot_market = CreateOrderRequest {
+            tp_order_type: Some(OrderType::Market),
+            ..spot.clone()
+        };
+        assert!(spot_market.validate().is_err());
+
+        // Batch items take the batch's category
+        let batch = BatchCreateOrderRequest {
+            category: Product::Spot,
+            request: vec![CreateOrderRequest {
+                category: Product::UsdMFutures,
+                ..spot
+            }],
+        };
+        batch.validate()?;
+
+        let spot_amend = AmendOrderRequest {
+            category: Product::Spot,
+            ..unset.clone()
+        };
+        spot_amend.validate()?;
+        let batch = BatchAmendOrderRequest {
+            category: Product::Spot,
+            request: vec![unset],
+        };
+        batch.validate()?;
+    }
 }
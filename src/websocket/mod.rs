@@ -9,7 +9,7 @@ use fehler::{throw, throws};
 use futures::{stream::Stream, SinkExt, StreamExt};
 use hmac::{Hmac, Mac};
 use reqwest::Url;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use serde_json::{from_str, value::RawValue};
 use sha2::Sha256;
 use std::time::SystemTime;
@@ -68,7 +68,7 @@ where
                 .duration_since(SystemTime::UNIX_EPOCH)
                 .unwrap();
             let in_ms = since_epoch.as_millis() as u64;
-            let expires = in_ms + 1_000;
+            let expires = in_ms + config.recv_window;
 
             let mut mac =
                 Hmac::<Sha256>::new_from_slice(config.api_secret.unwrap().as_bytes()).unwrap();
@@ -111,24 +111,17 @@ impl<M> BybitWebsocket<M> {
 #[derive(Deserialize)]
 struct PublicMessage<'a> {
     pub topic: String,
-    #[serde(rename = "type")]
-    pub type_: String,
-    pub ts: u64,
     #[serde(borrow)]
     pub data: &'a RawValue,
 }
 
 #[derive(Deserialize)]
 struct PrivateMessage<'a> {
-    pub id: String,
     pub topic: String,
-    #[serde(rename = "creationTime")]
-    pub creation_time: u64,
     #[serde(borrow)]
     pub data: &'a RawValue,
 }
 
-
 impl<M> Stream for BybitWebsocket<M>
 where
     M: ParseMessage + Unpin + std::fmt::Debug,
@@ -150,18 +143,14 @@ where
         };
 
         if self.private {
-            let try_message = from_str::<PrivateMessage>(&msg);
-            if try_message.is_ok() {
-                let message = try_message.unwrap();
+            if let Ok(message) = from_str::<PrivateMessage>(&msg) {
                 Poll::Ready(Some(M::parse(&message.topic, message.data.get())))
             } else {
                 // auth/sub success
                 Poll::Ready(Some(Ok(M::parse_succ(&msg).unwrap())))
             }
         } else {
-            let try_message = from_str::<PublicMessage>(&msg);
-            if try_message.is_ok() {
-                let message = try_message.unwrap();
+            if let Ok(message) = from_str::<PublicMessage>(&msg) {
                 Poll::Ready(Some(M::parse(&message.topic, message.data.get())))
             } else {
                 Poll::Ready(Some(Ok(M::parse_succ(&msg).unwrap())))
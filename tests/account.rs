@@ -1,15 +1,10 @@
 use anyhow::Error;
-use bybit_async::{
-    rest,
-    Bybit,
-};
 use fehler::throws;
-use std::env::var;
 
 #[throws(Error)]
 #[tokio::test]
 async fn get_account_spot() {
-    env_logger::init();
+    let _ = env_logger::try_init();
 
     // let bybit = Bybit::with_key_and_secret(&var("BYBIT_KEY")?, &var("BYBIT_SECRET")?);
     // let resp = bybit.request(rest::GetAccountRequest {}).await?;
@@ -19,9 +14,14 @@ async fn get_account_spot() {
 #[throws(Error)]
 #[tokio::test]
 async fn get_account_usdm() {
-    env_logger::init();
+    let _ = env_logger::try_init();
 
     // let bybit = Bybit::with_key_and_secret(&var("BYBIT_KEY")?, &var("BYBIT_SECRET")?);
-    // let resp = bybit.request(rest::account::AccountInformationV2Request {}).await?;
+    // let resp = bybit
+    //     .request(rest::account::WalletBalanceRequest {
+    //         account_type: AccountType::Unified,
+    //         ..Default::default()
+    //     })
+    //     .await?;
     // println!("{resp:?}");
 }
@@ -0,0 +1,83 @@
+use anyhow::Error;
+use fehler::throws;
+use serde_json::{json, Value};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+use url::Url;
+
+/// A local REST server answering every request with the V5 envelope around `handler(url)`.
+pub struct StubServer {
+    pub endpoint: String,
+    requests: Arc<AtomicUsize>,
+}
+
+impl StubServer {
+    #[throws(Error)]
+    pub async fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&Url) -> Value + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let endpoint = format!("http://{}", listener.local_addr()?);
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let handler = Arc::new(handler);
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(respond(socket, handler.clone()));
+            }
+        });
+
+        Self { endpoint, requests }
+    }
+
+    /// The number of requests received so far.
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+#[throws(Error)]
+async fn respond<F>(mut socket: TcpStream, handler: Arc<F>)
+where
+    F: Fn(&Url) -> Value,
+{
+    let mut buf = vec![0; 4096];
+    let n = socket.read(&mut buf).await?;
+    let req = String::from_utf8_lossy(&buf[..n]);
+    let target = req.split_whitespace().nth(1).unwrap_or_default();
+    let url = Url::parse(&format!("http://stub{target}"))?;
+
+    let body = json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": handler(&url),
+        "retExtInfo": {},
+        "time": 0,
+    })
+    .to_string();
+
+    socket
+        .write_all(
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .as_bytes(),
+        )
+        .await?;
+}
+
+/// The integer query parameter `name`.
+pub fn param(url: &Url, name: &str) -> Option<i64> {
+    url.query_pairs()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.parse().unwrap())
+}
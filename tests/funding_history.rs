@@ -0,0 +1,64 @@
+mod common;
+
+use anyhow::Error;
+use bybit_async::{models::Product, Bybit};
+use chrono::{DateTime, Duration, Utc};
+use common::{param, StubServer};
+use fehler::throws;
+use futures::TryStreamExt;
+use serde_json::{json, Value};
+use url::Url;
+
+const LISTED: i64 = 1672531200000; // 2023-01-01T00:00:00Z
+const EIGHT_HOURS: i64 = 8 * 3600 * 1000;
+
+// Answers /v5/market/funding/history with one rate every 8 hours since `LISTED`,
+// at most `limit` rows up to `endTime`, newest first.
+fn handle(url: &Url) -> Value {
+    let start = param(url, "startTime").unwrap_or(LISTED).max(LISTED);
+    let end = param(url, "endTime").unwrap();
+    let first = start + (LISTED - start).rem_euclid(EIGHT_HOURS);
+    let mut rows: Vec<_> = (first..=end)
+        .step_by(EIGHT_HOURS as usize)
+        .map(|t| json!({"symbol": "BTCUSDT", "fundingRate": "0.0001", "fundingRateTimestamp": t.to_string()}))
+        .collect();
+    rows.reverse();
+    rows.truncate(param(url, "limit").unwrap() as usize);
+    json!({"category": "linear", "list": rows})
+}
+
+#[throws(Error)]
+#[tokio::test]
+async fn funding_history() {
+    let _ = env_logger::try_init();
+
+    let server = StubServer::start(handle).await?;
+    let bybit = Bybit::builder()
+        .rest_api_endpoint(&server.endpoint)
+        .build()?;
+    let listed = DateTime::<Utc>::from_timestamp_millis(LISTED).unwrap();
+    let end = listed + Duration::days(100);
+
+    // 301 rates from the listing to `end`, both inclusive
+    let rates: Vec<_> = bybit
+        .funding_history(Product::UsdMFutures, "BTCUSDT", None, Some(end))
+        .try_collect()
+        .await?;
+    assert_eq!(server.requests(), 2);
+    assert_eq!(rates.len(), 301);
+    assert_eq!(rates[0].funding_rate_timestamp, end);
+    assert_eq!(rates[300].funding_rate_timestamp, listed);
+    assert!(rates
+        .windows(2)
+        .all(|w| w[0].funding_rate_timestamp - w[1].funding_rate_timestamp == Duration::hours(8)));
+
+    // Exactly one page within the range stops without another request
+    let start = end - Duration::hours(8 * 199);
+    let rates: Vec<_> = bybit
+        .funding_history(Product::UsdMFutures, "BTCUSDT", Some(start), Some(end))
+        .try_collect()
+        .await?;
+    assert_eq!(server.requests(), 3);
+    assert_eq!(rates.len(), 200);
+    assert_eq!(rates[199].funding_rate_timestamp, start);
+}
@@ -1,7 +1,5 @@
 use anyhow::Error;
-use bybit_async::{rest, Bybit};
 use fehler::throws;
-use std::env::var;
 
 #[throws(Error)]
 #[tokio::test]
@@ -9,8 +7,13 @@ async fn get_current_position_mode() {
     env_logger::init();
 
     // let bybit = Bybit::with_key_and_secret(&var("BYBIT_KEY")?, &var("BYBIT_SECRET")?);
-    // let resp = bybit
-    //     .request(::GetCurrentPositionModeRequest {})
+    // let positions: Vec<_> = bybit
+    //     .paginate(rest::position::PositionListRequest {
+    //         category: Product::UsdMFutures,
+    //         settle_coin: Some("USDT".into()),
+    //         ..Default::default()
+    //     })
+    //     .try_collect()
     //     .await?;
-    // println!("{resp:?}");
+    // println!("{positions:?}");
 }
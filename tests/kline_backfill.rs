@@ -0,0 +1,58 @@
+mod common;
+
+use anyhow::Error;
+use bybit_async::{
+    models::{market::KlineInterval, Product},
+    Bybit,
+};
+use chrono::{DateTime, Duration, Utc};
+use common::{param, StubServer};
+use fehler::throws;
+use futures::TryStreamExt;
+use serde_json::{json, Value};
+use url::Url;
+
+// Answers /v5/market/kline with one candle per minute from one minute before `start`
+// (overlapping the previous window) to `end`, newest first.
+fn handle(url: &Url) -> Value {
+    let mut rows: Vec<_> = (param(url, "start").unwrap() - 60_000..=param(url, "end").unwrap())
+        .step_by(60_000)
+        .map(|t| json!([t.to_string(), "1", "2", "0.5", "1.5", "10", "15"]))
+        .collect();
+    rows.reverse();
+    json!({"symbol": "BTCUSDT", "category": "linear", "list": rows})
+}
+
+#[throws(Error)]
+#[tokio::test]
+async fn kline_backfill() {
+    let _ = env_logger::try_init();
+
+    let server = StubServer::start(handle).await?;
+    let bybit = Bybit::builder()
+        .rest_api_endpoint(&server.endpoint)
+        .build()?;
+    let start: DateTime<Utc> = "2023-01-01T00:00:00Z".parse()?;
+    let end: DateTime<Utc> = "2023-01-03T00:00:00Z".parse()?;
+    let klines: Vec<_> = bybit
+        .kline_backfill(
+            Product::UsdMFutures,
+            "BTCUSDT",
+            KlineInterval::Min1,
+            start..end,
+            2,
+        )
+        .try_collect()
+        .await?;
+
+    assert_eq!(server.requests(), 3);
+    assert_eq!(klines.len(), 2 * 24 * 60);
+    assert_eq!(klines[0].start_time, start);
+    assert_eq!(
+        klines[klines.len() - 1].start_time,
+        end - Duration::minutes(1)
+    );
+    assert!(klines
+        .windows(2)
+        .all(|w| w[1].start_time - w[0].start_time == Duration::minutes(1)));
+}
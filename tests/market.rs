@@ -1,13 +1,18 @@
 use anyhow::Error;
-use bybit_async::{rest, Bybit};
 use fehler::throws;
 
 #[throws(Error)]
 #[tokio::test]
-async fn exchange_information() {
+async fn instruments_info() {
     env_logger::init();
 
     // let bybit = Bybit::new();
-    // let resp = bybit.request(rest::trade::ExchangeInformationRequest {}).await?;
+    // let resp = bybit
+    //     .paginate(rest::market::InstrumentsInfoRequest {
+    //         category: Product::UsdMFutures,
+    //         ..Default::default()
+    //     })
+    //     .try_collect::<Vec<_>>()
+    //     .await?;
     // println!("{resp:?}");
 }
@@ -1,5 +1,4 @@
 use anyhow::Error;
-use bybit_async::{rest, Bybit};
 use fehler::throws;
 
 #[throws(Error)]
@@ -1,17 +1,22 @@
 use anyhow::Error;
-use bybit_async::{rest, Bybit};
+use bybit_async::{
+    models::{DcpProduct, Product},
+    rest, Bybit,
+};
 use fehler::throws;
 use std::env::var;
 
 #[throws(Error)]
 #[tokio::test]
-async fn cancel_all_open_orders() {
-    env_logger::init();
+async fn cancel_all_orders() {
+    let _ = env_logger::try_init();
 
     let bybit = Bybit::with_key_and_secret(&var("BYBIT_KEY")?, &var("BYBIT_SECRET")?);
     let resp = bybit
-        .request(rest::trade::CancelAllOpenOrdersRequest {
-            symbol: "BTCUSDT".into(),
+        .request(rest::trade::CancelAllOrdersRequest {
+            category: Product::UsdMFutures,
+            symbol: Some("BTCUSDT".into()),
+            ..Default::default()
         })
         .await?;
     println!("{resp:?}");
@@ -19,14 +24,14 @@ async fn cancel_all_open_orders() {
 
 #[throws(Error)]
 #[tokio::test]
-async fn auto_cancel_all_open_orders() {
-    env_logger::init();
+async fn disconnected_cancel_all() {
+    let _ = env_logger::try_init();
 
     let bybit = Bybit::with_key_and_secret(&var("BYBIT_KEY")?, &var("BYBIT_SECRET")?);
     let resp = bybit
-        .request(rest::trade::AutoCancelAllOpenOrdersRequest {
-            symbol: "BTCUSDT".into(),
-            countdown_time: 1000000,
+        .request(rest::trade::DisconnectedCancelAllRequest {
+            product: Some(DcpProduct::Derivatives),
+            time_window: 300,
         })
         .await?;
     println!("{resp:?}");
@@ -4,8 +4,6 @@ use bybit_async::websocket::{topics::WebsocketMessage, BybitWebsocket};
 use bybit_async::Config;
 use fehler::throws;
 use futures::StreamExt;
-use std::time::Duration;
-use tokio::time::timeout;
 
 #[throws(Error)]
 #[tokio::test]